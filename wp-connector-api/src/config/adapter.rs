@@ -12,4 +12,57 @@ pub trait ConnectorKindAdapter: Send + Sync {
     fn url_to_params(&self, _url: &str) -> anyhow::Result<ParamMap> {
         Ok(ParamMap::new())
     }
+    /// Keys that must be present (and non-null) in the resolved params.
+    fn required_params(&self) -> &[&'static str] {
+        &[]
+    }
+    /// Check [`Self::required_params`] against `params`; the error lists every missing key.
+    fn validate_params(&self, params: &ParamMap) -> Result<(), String> {
+        check_required_params(self.required_params().iter().copied(), params)
+    }
+}
+
+/// Missing and `null` values both count as absent; all offenders are reported, comma-separated.
+pub(crate) fn check_required_params<'a>(
+    required: impl IntoIterator<Item = &'a str>,
+    params: &ParamMap,
+) -> Result<(), String> {
+    let missing: Vec<&str> = required
+        .into_iter()
+        .filter(|k| params.get(*k).is_none_or(|v| v.is_null()))
+        .collect();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("missing required params: {}", missing.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct KafkaAdapter;
+
+    impl ConnectorKindAdapter for KafkaAdapter {
+        fn kind(&self) -> &'static str {
+            "kafka"
+        }
+        fn required_params(&self) -> &[&'static str] {
+            &["brokers", "topic"]
+        }
+    }
+
+    #[test]
+    fn adapter_validate_params_lists_missing_keys() {
+        let mut params = ParamMap::new();
+        params.insert("topic".into(), json!(null));
+        let err = KafkaAdapter.validate_params(&params).unwrap_err();
+        assert_eq!(err, "missing required params: brokers, topic");
+
+        params.insert("brokers".into(), json!("localhost:9092"));
+        params.insert("topic".into(), json!("events"));
+        assert!(KafkaAdapter.validate_params(&params).is_ok());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::runtime::source::Tags;
 use crate::types::ParamMap;
 
 // Helpers: convert from TOML to ParamMap (serde_json)
@@ -35,9 +36,117 @@ fn table_to_param_map(t: &toml::value::Table) -> ParamMap {
     out
 }
 
+/// Parse a JSON document whose top level must be an object.
+pub fn parammap_from_json_str(s: &str) -> anyhow::Result<ParamMap> {
+    let value: serde_json::Value = serde_json::from_str(s)?;
+    object_to_param_map(value, "json")
+}
+
+/// Parse a YAML document (e.g. a ConfigMap entry) whose top level must be a mapping.
+pub fn parammap_from_yaml_str(s: &str) -> anyhow::Result<ParamMap> {
+    let value: serde_json::Value = serde_yaml_ng::from_str(s)?;
+    object_to_param_map(value, "yaml")
+}
+
+fn object_to_param_map(value: serde_json::Value, format: &str) -> anyhow::Result<ParamMap> {
+    match value {
+        serde_json::Value::Object(map) => Ok(map.into_iter().collect()),
+        // Only the type is reported: the value itself may carry secrets.
+        other => anyhow::bail!(
+            "{format} params must be an object at the top level, got: {}",
+            json_type_name(&other)
+        ),
+    }
+}
+
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "bool",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// Convert tags into a TOML table of string values (e.g. a `[source.tags]` section).
+pub fn tags_to_toml_table(tags: &Tags) -> toml::value::Table {
+    tags.iter()
+        .map(|(k, v)| (k.to_string(), toml::Value::String(v.to_string())))
+        .collect()
+}
+
+/// Build tags from a TOML table; non-string values are skipped.
+pub fn tags_from_toml_table(table: &toml::value::Table) -> Tags {
+    table
+        .iter()
+        .filter_map(|(k, v)| v.as_str().map(|s| (k.as_str(), s)))
+        .collect()
+}
+
+/// Layer `overrides` on top of `base` into a new map; keys in `overrides` win. Inputs are untouched.
+pub fn parammap_merge(base: &ParamMap, overrides: &ParamMap) -> ParamMap {
+    let mut out = base.clone();
+    out.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+    out
+}
+
+/// Key-level difference between two ParamMaps; each list is in key order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParamDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Keys present on both sides whose values are not equal.
+    pub changed: Vec<String>,
+}
+
+/// Compare `old` against `new` by key.
+pub fn parammap_diff(old: &ParamMap, new: &ParamMap) -> ParamDiff {
+    let mut diff = ParamDiff::default();
+    for (k, v) in new {
+        match old.get(k) {
+            None => diff.added.push(k.clone()),
+            Some(prev) if prev != v => diff.changed.push(k.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = old
+        .keys()
+        .filter(|k| !new.contains_key(*k))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Read the listed environment variables into a ParamMap.
+///
+/// Keys are lowercased after stripping `prefix` followed by one `_`; names that do not start
+/// with `{prefix}_` keep their full name. Unset variables are skipped.
+pub(crate) fn parammap_from_env(prefix: Option<&str>, env_keys: &[String]) -> ParamMap {
+    let mut out = ParamMap::new();
+    for var in env_keys {
+        let Ok(val) = std::env::var(var) else {
+            continue;
+        };
+        let name = prefix
+            .and_then(|p| var.strip_prefix(p))
+            .and_then(|rest| rest.strip_prefix('_'))
+            .unwrap_or(var);
+        out.insert(name.to_lowercase(), serde_json::Value::String(val));
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{parammap_from_toml_map, parammap_from_toml_table};
+    use super::{
+        ParamDiff, parammap_diff, parammap_from_env, parammap_from_json_str,
+        parammap_from_toml_map, parammap_from_toml_table, parammap_from_yaml_str, parammap_merge,
+        tags_from_toml_table, tags_to_toml_table,
+    };
+    use crate::runtime::source::Tags;
+    use crate::types::ParamMap;
     use serde_json::json;
     use std::collections::BTreeMap;
     use toml::value::{Datetime, Table, Value};
@@ -87,4 +196,139 @@ mod tests {
         let table_version = parammap_from_toml_table(table);
         assert_eq!(map_version, table_version);
     }
+
+    #[test]
+    fn parammap_from_json_and_yaml_str_roundtrip() {
+        let expected = params(&[
+            ("name", json!("kafka")),
+            ("port", json!(9092)),
+            ("ratio", json!(0.25)),
+            ("tls", json!(true)),
+            ("topics", json!(["a", "b"])),
+            ("auth", json!({"user": "u", "retries": 3})),
+        ]);
+
+        let text = serde_json::to_string(&expected).unwrap();
+        assert_eq!(parammap_from_json_str(&text).unwrap(), expected);
+
+        let yaml = "name: kafka\nport: 9092\nratio: 0.25\ntls: true\n\
+                    topics: [a, b]\nauth:\n  user: u\n  retries: 3\n";
+        assert_eq!(parammap_from_yaml_str(yaml).unwrap(), expected);
+    }
+
+    #[test]
+    fn parammap_from_str_rejects_non_objects() {
+        let err = parammap_from_json_str("[1, 2]").unwrap_err();
+        assert!(err.to_string().contains("must be an object"));
+        let err = parammap_from_json_str(r#""password=hunter2""#).unwrap_err();
+        assert!(err.to_string().ends_with("got: string"));
+        assert!(!err.to_string().contains("hunter2"));
+        assert!(parammap_from_json_str("{not json").is_err());
+        assert!(parammap_from_yaml_str("- a\n- b\n").is_err());
+        assert!(parammap_from_yaml_str("42").is_err());
+    }
+
+    fn params(pairs: &[(&str, serde_json::Value)]) -> ParamMap {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn parammap_merge_prefers_overrides_without_touching_inputs() {
+        let base = params(&[("host", json!("localhost")), ("port", json!(80))]);
+        let overrides = params(&[("port", json!(8080)), ("tls", json!(true))]);
+        let (base_before, overrides_before) = (base.clone(), overrides.clone());
+
+        let merged = parammap_merge(&base, &overrides);
+        assert_eq!(
+            merged,
+            params(&[
+                ("host", json!("localhost")),
+                ("port", json!(8080)),
+                ("tls", json!(true)),
+            ])
+        );
+        assert_eq!(base, base_before);
+        assert_eq!(overrides, overrides_before);
+    }
+
+    #[test]
+    fn parammap_diff_reports_added_removed_changed() {
+        let old = params(&[
+            ("host", json!("a")),
+            ("port", json!(80)),
+            ("path", json!("/")),
+            ("debug", json!(false)),
+        ]);
+        let new = params(&[
+            ("host", json!("a")),
+            ("port", json!(81)),
+            ("debug", json!(true)),
+            ("tls", json!(true)),
+            ("ca", json!("x.pem")),
+        ]);
+        assert_eq!(
+            parammap_diff(&old, &new),
+            ParamDiff {
+                added: vec!["ca".into(), "tls".into()],
+                removed: vec!["path".into()],
+                changed: vec!["debug".into(), "port".into()],
+            }
+        );
+        assert_eq!(parammap_diff(&old, &old), ParamDiff::default());
+    }
+
+    #[test]
+    fn parammap_from_env_strips_prefix_and_skips_missing() {
+        unsafe {
+            std::env::set_var("WPTEST_PARAM_HOST", "db.local");
+        }
+        let keys = vec!["WPTEST_PARAM_HOST".into(), "WPTEST_PARAM_UNSET".into()];
+
+        let plain = parammap_from_env(None, &keys);
+        assert_eq!(plain.get("wptest_param_host"), Some(&json!("db.local")));
+        assert_eq!(plain.len(), 1);
+
+        let prefixed = parammap_from_env(Some("WPTEST_PARAM"), &keys);
+        assert_eq!(prefixed.get("host"), Some(&json!("db.local")));
+    }
+
+    #[test]
+    fn parammap_from_env_requires_underscore_after_prefix() {
+        unsafe {
+            std::env::set_var("WPTEST_APPLE_KEY", "red");
+            std::env::set_var("WPTEST_APP__SECRET", "s3");
+        }
+        let keys = vec!["WPTEST_APPLE_KEY".into(), "WPTEST_APP__SECRET".into()];
+        let params = parammap_from_env(Some("WPTEST_APP"), &keys);
+        assert_eq!(params.get("wptest_apple_key"), Some(&json!("red")));
+        assert_eq!(params.get("_secret"), Some(&json!("s3")));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn tags_roundtrip_through_toml() {
+        let mut tags = Tags::new();
+        tags.set("env", "prod");
+        tags.set("region", "us-east");
+
+        let text = toml::to_string(&tags_to_toml_table(&tags)).unwrap();
+        assert!(text.contains("env = \"prod\""));
+        let table: Table = toml::from_str(&text).unwrap();
+        let parsed = tags_from_toml_table(&table);
+        assert_eq!(
+            parsed.iter().collect::<Vec<_>>(),
+            tags.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn tags_from_toml_table_skips_non_string_values() {
+        let table: Table = toml::from_str("env = \"prod\"\nport = 8080\nlist = [\"a\"]").unwrap();
+        let tags = tags_from_toml_table(&table);
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags.get("env"), Some("prod"));
+    }
 }
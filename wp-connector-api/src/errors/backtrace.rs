@@ -0,0 +1,108 @@
+use orion_error::{DomainReason, StructError};
+use std::backtrace::Backtrace;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+
+/// 为 `SourceError` / `SinkError` 附加调用栈（需启用 `backtrace` feature）。
+///
+/// `StructError` 来自 orion-error，无法增加字段，因此调用栈保存在包装类型
+/// [`Backtraced`] 中，不写入 `detail`，也不出现在 `Display` 输出里。
+pub trait ErrorBacktraceExt: Sized {
+    /// 捕获当前调用栈；显式调用即视为开启，不受 `RUST_BACKTRACE` 影响。
+    fn with_backtrace(self) -> Backtraced<Self>;
+}
+
+impl<R: DomainReason> ErrorBacktraceExt for StructError<R> {
+    fn with_backtrace(self) -> Backtraced<Self> {
+        Backtraced {
+            error: self,
+            backtrace: Backtrace::force_capture(),
+        }
+    }
+}
+
+/// 携带调用栈的错误；`Deref` 到原错误，`Display` 与原错误一致，`Debug` 额外输出调用栈。
+pub struct Backtraced<E> {
+    error: E,
+    backtrace: Backtrace,
+}
+
+impl<E> Backtraced<E> {
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// 丢弃调用栈，取回原错误
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Deref for Backtraced<E> {
+    type Target = E;
+
+    fn deref(&self) -> &E {
+        &self.error
+    }
+}
+
+impl<E: Display> Display for Backtraced<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: Debug> Debug for Backtraced<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Backtraced")
+            .field("error", &self.error)
+            .field("backtrace", &self.backtrace)
+            .finish()
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for Backtraced<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SinkError, SinkReason, SourceError, SourceReason};
+
+    #[inline(never)]
+    fn outer() -> Backtraced<SourceError> {
+        inner()
+    }
+
+    #[inline(never)]
+    fn inner() -> Backtraced<SourceError> {
+        SourceError::from(SourceReason::Disconnect("x".into())).with_backtrace()
+    }
+
+    #[test]
+    fn backtrace_captures_calling_test_function() {
+        let err = outer();
+        let trace = err.backtrace().to_string();
+        assert!(trace.contains("backtrace_captures_calling_test_function"));
+        assert_eq!(err.reason(), &SourceReason::Disconnect("x".into()));
+    }
+
+    #[test]
+    fn backtrace_leaves_detail_and_display_untouched() {
+        let make = || SinkError::from(SinkReason::Sink("flush".into())).with_detail("io timeout");
+        let err = make().with_backtrace();
+        assert_eq!(err.detail().as_deref(), Some("io timeout"));
+        assert_eq!(err.to_string(), make().to_string());
+        assert!(format!("{err:?}").contains("backtrace"));
+
+        let twice = err.into_inner().with_backtrace();
+        assert_eq!(twice.error().detail().as_deref(), Some("io timeout"));
+    }
+}
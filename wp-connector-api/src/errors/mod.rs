@@ -1,6 +1,11 @@
 // Centralized error module for wp-connector-api
+#[cfg(feature = "backtrace")]
+mod backtrace;
 pub mod sink;
 pub mod source;
 
+#[cfg(feature = "backtrace")]
+pub use backtrace::{Backtraced, ErrorBacktraceExt};
+
 pub use sink::{ReasonSummary, SinkError, SinkErrorOwe, SinkReason, SinkResult};
 pub use source::{SourceError, SourceReason, SourceResult};
@@ -3,6 +3,7 @@ use orion_error::StructError;
 use orion_error::{ErrorCode, UvsReason};
 use serde::Serialize;
 use std::sync::mpsc::SendError;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Serialize, From)]
@@ -13,6 +14,11 @@ pub enum SinkReason {
     Mock,
     #[error("stg ctrl error")]
     StgCtrl,
+    #[from(skip)]
+    #[error("sink timeout after {0:?}")]
+    Timeout(Duration),
+    #[error("sink throttled")]
+    Throttled,
     #[error("{0}")]
     Uvs(UvsReason),
 }
@@ -28,6 +34,10 @@ impl ErrorCode for SinkReason {
             // Storage control errors
             SinkReason::StgCtrl => 510, // Storage control error
 
+            // Transient errors, worth retrying
+            SinkReason::Timeout(_) => 408, // Write/flush timed out
+            SinkReason::Throttled => 429,  // Backend rate limited us
+
             // Delegate to wrapped reason
             SinkReason::Uvs(r) => r.error_code(),
         }
@@ -55,12 +65,48 @@ impl From<anyhow::Error> for SinkReason {
     }
 }
 
+/// 按 IO 错误类型归类为 `SinkReason::Sink`。
+///
+/// `SinkError` 是外部类型 `StructError` 的别名，无法直接实现 `From<io::Error>`；
+/// 需要 `SinkError` 时使用 `SinkError::from(SinkReason::from(e))`。
+impl From<std::io::Error> for SinkReason {
+    fn from(e: std::io::Error) -> Self {
+        use std::io::ErrorKind;
+        match e.kind() {
+            ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset | ErrorKind::BrokenPipe => {
+                SinkReason::Sink(format!("connection error: {e}"))
+            }
+            ErrorKind::TimedOut => SinkReason::Sink("write timeout".into()),
+            ErrorKind::PermissionDenied => SinkReason::Sink("permission denied".into()),
+            _ => SinkReason::Sink(e.to_string()),
+        }
+    }
+}
+
 pub type SinkResult<T> = Result<T, SinkError>;
 
 impl SinkReason {
     pub fn sink<S: Into<String>>(msg: S) -> Self {
         SinkReason::Sink(msg.into())
     }
+
+    /// 是否为可重试的瞬时错误（`Timeout`、`Throttled`）
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, SinkReason::Timeout(_) | SinkReason::Throttled)
+    }
+
+    /// Localized message for UIs; supports `"en"` and `"zh-CN"` (alias `"zh"`),
+    /// other locales fall back to the English `Display` text.
+    pub fn localized_message(&self, locale: &str) -> String {
+        match (self, locale) {
+            (SinkReason::Sink(msg), "zh-CN" | "zh") => format!("输出端不可用：{msg}"),
+            (SinkReason::Mock, "zh-CN" | "zh") => "模拟错误".into(),
+            (SinkReason::StgCtrl, "zh-CN" | "zh") => "存储控制错误".into(),
+            (SinkReason::Timeout(d), "zh-CN" | "zh") => format!("输出超时：{d:?}"),
+            (SinkReason::Throttled, "zh-CN" | "zh") => "输出端限流".into(),
+            _ => self.to_string(),
+        }
+    }
 }
 
 pub trait SinkErrorOwe<T> {
@@ -119,11 +165,73 @@ mod tests {
         assert_eq!(detail.as_ref().map(|s| s.as_str()), Some("io timeout"));
     }
 
+    #[test]
+    fn sink_reason_from_io_error_kinds() {
+        use std::io::{Error, ErrorKind};
+
+        for kind in [
+            ErrorKind::ConnectionRefused,
+            ErrorKind::ConnectionReset,
+            ErrorKind::BrokenPipe,
+        ] {
+            match SinkReason::from(Error::new(kind, "peer gone")) {
+                SinkReason::Sink(msg) => assert_eq!(msg, "connection error: peer gone"),
+                other => panic!("unexpected reason: {other:?}"),
+            }
+        }
+        assert_eq!(
+            SinkReason::from(Error::new(ErrorKind::TimedOut, "slow")),
+            SinkReason::Sink("write timeout".into())
+        );
+        assert_eq!(
+            SinkReason::from(Error::new(ErrorKind::PermissionDenied, "ro")),
+            SinkReason::Sink("permission denied".into())
+        );
+        assert_eq!(
+            SinkReason::from(Error::other("disk full")),
+            SinkReason::Sink("disk full".into())
+        );
+
+        let err = SinkError::from(SinkReason::from(Error::new(ErrorKind::TimedOut, "t")));
+        assert_eq!(err.reason(), &SinkReason::Sink("write timeout".into()));
+    }
+
+    #[test]
+    fn sink_reason_localized_message() {
+        let reason = SinkReason::Sink("kafka".into());
+        let zh = reason.localized_message("zh-CN");
+        assert!(zh.contains("kafka"));
+        assert_ne!(zh, reason.to_string());
+        assert_eq!(reason.localized_message("fr"), "sink unavailable kafka");
+        assert_eq!(
+            SinkReason::StgCtrl.localized_message("en"),
+            "stg ctrl error"
+        );
+    }
+
     #[test]
     fn sink_reason_error_codes() {
         assert_eq!(SinkReason::Sink("test".into()).error_code(), 500);
         assert_eq!(SinkReason::Mock.error_code(), 599);
         assert_eq!(SinkReason::StgCtrl.error_code(), 510);
+        assert_eq!(
+            SinkReason::Timeout(Duration::from_secs(3)).error_code(),
+            408
+        );
+        assert_eq!(SinkReason::Throttled.error_code(), 429);
+    }
+
+    #[test]
+    fn sink_reason_is_retryable() {
+        assert!(SinkReason::Timeout(Duration::from_millis(500)).is_retryable());
+        assert!(SinkReason::Throttled.is_retryable());
+        assert!(!SinkReason::Sink("down".into()).is_retryable());
+        assert!(!SinkReason::Mock.is_retryable());
+        assert!(!SinkReason::StgCtrl.is_retryable());
+        assert_eq!(
+            SinkReason::Timeout(Duration::from_secs(2)).to_string(),
+            "sink timeout after 2s"
+        );
     }
 
     #[test]
@@ -132,6 +240,8 @@ mod tests {
             SinkReason::Sink("x".into()).error_code(),
             SinkReason::Mock.error_code(),
             SinkReason::StgCtrl.error_code(),
+            SinkReason::Timeout(Duration::ZERO).error_code(),
+            SinkReason::Throttled.error_code(),
         ];
         // Verify all codes are different
         let mut unique = codes.clone();
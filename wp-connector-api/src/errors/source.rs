@@ -1,6 +1,7 @@
 use derive_more::From;
 use orion_error::{ErrorCode, StructError, UvsReason};
 use serde::Serialize;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug, Clone, PartialEq, Serialize, From)]
@@ -17,6 +18,12 @@ pub enum SourceReason {
     #[from(skip)]
     #[error("{0}")]
     Other(String),
+    #[from(skip)]
+    #[error("source timeout after {0:?}")]
+    Timeout(Duration),
+    #[from(skip)]
+    #[error("auth failure: {0}")]
+    AuthFailure(String),
     #[error("{0}")]
     Uvs(UvsReason),
 }
@@ -30,6 +37,10 @@ impl ErrorCode for SourceReason {
 
             // Retryable errors
             SourceReason::Disconnect(_) => 503, // Connection lost, can retry
+            SourceReason::Timeout(_) => 408,    // Read timed out, can retry
+
+            // Permanent until credentials change
+            SourceReason::AuthFailure(_) => 401, // Rejected credentials
 
             // Internal/supplier errors
             SourceReason::SupplierError(_) => 500, // Upstream supplier error
@@ -41,6 +52,27 @@ impl ErrorCode for SourceReason {
     }
 }
 
+impl SourceReason {
+    /// 是否为可重试的瞬时错误（`Timeout`、`Disconnect`）
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, SourceReason::Timeout(_) | SourceReason::Disconnect(_))
+    }
+
+    /// 返回本地化错误信息；支持 `"en"` 与 `"zh-CN"`（别名 `"zh"`），其他 locale 回退英文。
+    pub fn localized_message(&self, locale: &str) -> String {
+        match (self, locale) {
+            (SourceReason::NotData, "zh-CN" | "zh") => "暂无数据".into(),
+            (SourceReason::EOF, "zh-CN" | "zh") => "数据流结束".into(),
+            (SourceReason::SupplierError(msg), "zh-CN" | "zh") => format!("数据供应方错误：{msg}"),
+            (SourceReason::Disconnect(msg), "zh-CN" | "zh") => format!("连接断开：{msg}"),
+            (SourceReason::Other(msg), "zh-CN" | "zh") => format!("其他错误：{msg}"),
+            (SourceReason::Timeout(d), "zh-CN" | "zh") => format!("读取超时：{d:?}"),
+            (SourceReason::AuthFailure(msg), "zh-CN" | "zh") => format!("认证失败：{msg}"),
+            _ => self.to_string(),
+        }
+    }
+}
+
 pub type SourceError = StructError<SourceReason>;
 pub type SourceResult<T> = Result<T, StructError<SourceReason>>;
 
@@ -66,6 +98,15 @@ mod tests {
             500
         );
         assert_eq!(SourceReason::Other("misc".into()).error_code(), 520);
+
+        assert_eq!(
+            SourceReason::Timeout(Duration::from_secs(1)).error_code(),
+            408
+        );
+        assert_eq!(
+            SourceReason::AuthFailure("bad token".into()).error_code(),
+            401
+        );
     }
 
     #[test]
@@ -76,6 +117,8 @@ mod tests {
             SourceReason::Disconnect("x".into()).error_code(),
             SourceReason::SupplierError("x".into()).error_code(),
             SourceReason::Other("x".into()).error_code(),
+            SourceReason::Timeout(Duration::ZERO).error_code(),
+            SourceReason::AuthFailure("x".into()).error_code(),
         ];
         // Verify all codes are different
         let mut unique = codes.clone();
@@ -90,6 +133,34 @@ mod tests {
         assert!(SourceReason::EOF.error_code() < 200);
     }
 
+    #[test]
+    fn source_reason_localized_message() {
+        let reason = SourceReason::Disconnect("host".into());
+        let zh = reason.localized_message("zh-CN");
+        assert!(zh.starts_with("连接断开"));
+        assert!(zh.contains("host"));
+        assert_eq!(reason.localized_message("fr"), reason.to_string());
+        assert_eq!(reason.localized_message("en"), "disconnected: host");
+
+        assert_eq!(SourceReason::NotData.localized_message("zh-CN"), "暂无数据");
+        assert_eq!(SourceReason::EOF.localized_message("zh"), "数据流结束");
+    }
+
+    #[test]
+    fn source_reason_is_retryable() {
+        assert!(SourceReason::Timeout(Duration::from_secs(5)).is_retryable());
+        assert!(SourceReason::Disconnect("x".into()).is_retryable());
+        assert!(!SourceReason::AuthFailure("x".into()).is_retryable());
+        assert!(!SourceReason::NotData.is_retryable());
+        assert!(!SourceReason::EOF.is_retryable());
+        assert!(!SourceReason::SupplierError("x".into()).is_retryable());
+        assert!(!SourceReason::Other("x".into()).is_retryable());
+        assert_eq!(
+            SourceReason::AuthFailure("x".into()).localized_message("zh"),
+            "认证失败：x"
+        );
+    }
+
     #[test]
     fn source_reason_retryable_codes_are_5xx() {
         let code = SourceReason::Disconnect("x".into()).error_code();
@@ -3,7 +3,13 @@ mod errors;
 mod runtime;
 mod types;
 // keep top-level convenient re-exports stable
-pub use config::param::{parammap_from_toml_map, parammap_from_toml_table};
+pub use config::param::{
+    ParamDiff, parammap_diff, parammap_from_json_str, parammap_from_toml_map,
+    parammap_from_toml_table, parammap_from_yaml_str, parammap_merge, tags_from_toml_table,
+    tags_to_toml_table,
+};
+#[cfg(feature = "backtrace")]
+pub use errors::{Backtraced, ErrorBacktraceExt};
 pub use errors::{
     ReasonSummary, SinkError, SinkErrorOwe, SinkReason, SinkResult, SourceError, SourceReason,
     SourceResult,
@@ -15,15 +21,25 @@ pub use errors::sink::{ReasonSummary as ErrorSummary, SinkErrorOwe as SinkResult
 // Config-time adapter (conn_url -> params)
 pub use config::adapter::ConnectorKindAdapter;
 pub use runtime::cnn::{ConnectorDef, ConnectorScope, SinkDefProvider, SourceDefProvider};
-pub use types::ParamMap;
+pub use types::{ParamMap, ParamMapExt};
+// Runtime: parse bridge
+pub use runtime::bridge::{BatchParser, NullParser, RawStrParser};
+// Runtime: batch processing stages
+pub use runtime::pipeline::{EventPipeline, EventStage, FilterStage, MapStage};
 // Runtime: sink side
 pub use runtime::sink::{
-    AsyncCtrl, AsyncRawDataSink, AsyncRecordSink, AsyncSink, ResolvedSinkSpec as SinkSpec,
-    SinkBuildCtx, SinkFactory, SinkHandle,
+    AsyncCtrl, AsyncRawDataSink, AsyncRecordSink, AsyncSink, DEFAULT_DRAIN_TIMEOUT, PoolStrategy,
+    ResolvedSinkSpec as SinkSpec, SinkAckToken, SinkBuildCtx, SinkFactory, SinkFanOut, SinkHandle,
+    SinkMeta, SinkPool, SinkSpecBuilder,
 };
 
 pub use runtime::source::{
-    AcceptorHandle, AckToken, ControlEvent, CtrlRx, DataSource, EventPreHook,
-    ResolvedSourceSpec as SourceSpec, SeekPosition, ServiceAcceptor, SourceBatch, SourceBuildCtx,
-    SourceCaps, SourceEvent, SourceFactory, SourceHandle, SourceMeta, SourceSvcIns, Tags,
+    AcceptorHandle, AckToken, BatchStats, ControlEvent, CtrlRx, DataSource, EventPreHook,
+    MergedSource, OffsetAckToken, OffsetSeekPosition, ResolvedSourceSpec as SourceSpec,
+    SeekPosition, ServiceAcceptor, SourceBatch, SourceBuildCtx, SourceCaps, SourceEvent,
+    SourceFactory, SourceHandle, SourceMeta, SourceSpecBuilder, SourceSvcIns, SourceSvcInsBuilder,
+    StreamingSource, Tags, TimestampSeekPosition, batch_stats, batches_stats, compact_batch,
+    filter_batch, filter_by_tags, limit_batch_by_weight, map_payload, merge_batches,
+    partition_by_src_key, promote_to_arc_bytes, retain_events, split_by_tag, stamp_batch,
+    stamp_batch_if_absent, stamp_batch_shared, total_weight,
 };
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use wp_model_core::model::{DataField, DataRecord};
+
+use crate::runtime::source::{SourceBatch, SourceEvent};
+
+/// Bridge between raw source events and typed records (parse tier).
+///
+/// Each event is returned together with its parsed record, or `None` when
+/// the parser produced nothing for it.
+#[async_trait]
+pub trait BatchParser: Send + Sync {
+    async fn parse_batch(&self, batch: SourceBatch) -> Vec<(SourceEvent, Option<DataRecord>)>;
+}
+
+/// Parser that never produces a record; useful as a pass-through placeholder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullParser;
+
+#[async_trait]
+impl BatchParser for NullParser {
+    async fn parse_batch(&self, batch: SourceBatch) -> Vec<(SourceEvent, Option<DataRecord>)> {
+        batch.into_iter().map(|event| (event, None)).collect()
+    }
+}
+
+/// Field name used by [`RawStrParser`].
+pub const RAW_FIELD: &str = "raw";
+
+/// Wraps each payload (lossy UTF-8) in a single `Chars` field named `"raw"`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RawStrParser;
+
+#[async_trait]
+impl BatchParser for RawStrParser {
+    async fn parse_batch(&self, batch: SourceBatch) -> Vec<(SourceEvent, Option<DataRecord>)> {
+        batch
+            .into_iter()
+            .map(|event| {
+                let field = DataField::from_chars(RAW_FIELD, event.payload.to_string());
+                (event, Some(DataRecord::from(vec![field])))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::source::Tags;
+    use std::sync::Arc;
+    use wp_model_core::model::{DataType, Value};
+    use wp_parse_api::RawData;
+
+    fn make_batch() -> SourceBatch {
+        ["a=1", "b=2", "c=3"]
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                SourceEvent::new(
+                    i as u64,
+                    "bridge",
+                    RawData::from_string(*p),
+                    Arc::new(Tags::new()),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn null_parser_yields_no_records() {
+        let out = NullParser.parse_batch(make_batch()).await;
+        assert_eq!(out.len(), 3);
+        assert!(out.iter().all(|(_, rec)| rec.is_none()));
+    }
+
+    #[tokio::test]
+    async fn raw_str_parser_wraps_payload() {
+        let out = RawStrParser.parse_batch(make_batch()).await;
+        assert_eq!(out.len(), 3);
+        for (event, rec) in &out {
+            let rec = rec.as_ref().expect("record");
+            assert_eq!(rec.items.len(), 1);
+            let field = &rec.items[0];
+            assert_eq!(field.get_name(), RAW_FIELD);
+            assert_eq!(field.get_meta(), &DataType::Chars);
+            assert_eq!(
+                field.get_value(),
+                &Value::Chars(event.payload.to_string().into())
+            );
+        }
+        assert_eq!(out[1].0.event_id, 1);
+    }
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue, json};
+use wp_model_core::model::types::validate_json_schema;
 
 use crate::ParamMap;
+use crate::config::adapter::check_required_params;
 
 /// Defines whether a connector operates as a data source or sink.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -10,6 +13,20 @@ pub enum ConnectorScope {
     Source,
     /// Connector writes data
     Sink,
+    /// Connector both reads and writes data (e.g. an in-process channel)
+    Both,
+}
+
+impl ConnectorScope {
+    /// `true` for [`ConnectorScope::Source`] and [`ConnectorScope::Both`].
+    pub fn is_source(&self) -> bool {
+        matches!(self, Self::Source | Self::Both)
+    }
+
+    /// `true` for [`ConnectorScope::Sink`] and [`ConnectorScope::Both`].
+    pub fn is_sink(&self) -> bool {
+        matches!(self, Self::Sink | Self::Both)
+    }
 }
 
 /// Connector definition containing metadata and default configuration.
@@ -21,6 +38,7 @@ pub enum ConnectorScope {
 /// - `kind` is serialized as `"type"`
 /// - `default_params` is serialized as `"params"`
 /// - `scope` and `origin` are runtime-only fields (not serialized)
+/// - `schema` is omitted when `None`
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ConnectorDef {
     /// Unique identifier for this connector instance
@@ -28,7 +46,7 @@ pub struct ConnectorDef {
     /// Connector type (e.g., "kafka", "mysql", "elasticsearch")
     #[serde(rename = "type")]
     pub kind: String,
-    /// Whether this definition is for source or sink (runtime only)
+    /// Whether this definition is for source, sink or both (runtime only)
     #[serde(skip, default)]
     pub scope: ConnectorScope,
     /// Parameter keys that can be overridden at runtime
@@ -40,6 +58,9 @@ pub struct ConnectorDef {
     /// Origin identifier for tracking (runtime only)
     #[serde(skip, default)]
     pub origin: Option<String>,
+    /// JSON Schema describing `default_params` (only `properties.*.type` is checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<JsonValue>,
 }
 
 impl ConnectorDef {
@@ -48,8 +69,109 @@ impl ConnectorDef {
         self.scope = scope;
         self
     }
+
+    /// Whether `allow_override` is exactly `["*"]` (every parameter is overridable).
+    pub fn is_fully_open(&self) -> bool {
+        self.allow_override.len() == 1 && self.allow_override[0] == OVERRIDE_ANY
+    }
+
+    /// Whether `key` may be overridden; `"*"` in `allow_override` allows any key.
+    pub fn allows_override_of(&self, key: &str) -> bool {
+        self.allow_override
+            .iter()
+            .any(|k| k == OVERRIDE_ANY || k == key)
+    }
+
+    /// Check that every key in `overrides` is allowed by `allow_override`.
+    ///
+    /// Returns the first rejected key in the error message.
+    pub fn validate_overrides(&self, overrides: &ParamMap) -> Result<(), String> {
+        if self.is_fully_open() {
+            return Ok(());
+        }
+        match overrides.keys().find(|k| !self.allows_override_of(k)) {
+            Some(key) => Err(format!(
+                "connector '{}': parameter '{}' is not overridable",
+                self.id, key
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Merge `overrides` on top of `default_params` after [`Self::validate_overrides`].
+    pub fn resolve_params(&self, overrides: &ParamMap) -> Result<ParamMap, String> {
+        self.validate_overrides(overrides)?;
+        let mut params = self.default_params.clone();
+        params.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+        Ok(params)
+    }
+
+    /// Type-check `params` against `schema.properties` (depth 1, `type` only).
+    ///
+    /// Passes when `schema` is `None`; keys without a property entry are not checked.
+    /// Errors use `$.<key>: expected <type>, got <type>`.
+    pub fn validate_params_against_schema(&self, params: &ParamMap) -> Result<(), Vec<String>> {
+        let Some(JsonValue::Object(props)) = self.schema.as_ref().and_then(|s| s.get("properties"))
+        else {
+            return Ok(());
+        };
+        // 只保留顶层 `type`，避免递归进入嵌套 schema
+        let shallow: Map<String, JsonValue> = props
+            .iter()
+            .filter_map(|(k, sub)| Some((k.clone(), json!({ "type": sub.get("type")? }))))
+            .collect();
+        let values: Map<String, JsonValue> =
+            params.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+        validate_json_schema(
+            &JsonValue::Object(values),
+            &json!({ "type": "object", "properties": shallow }),
+        )
+    }
+
+    /// Keys listed in `schema.required`; empty without a schema.
+    pub fn required_params(&self) -> Vec<&str> {
+        self.schema
+            .as_ref()
+            .and_then(|s| s.get("required"))
+            .and_then(JsonValue::as_array)
+            .map(|keys| keys.iter().filter_map(JsonValue::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    /// Check that every [`Self::required_params`] key is present and non-null in `params`.
+    pub fn validate_params(&self, params: &ParamMap) -> Result<(), String> {
+        check_required_params(self.required_params(), params)
+            .map_err(|e| format!("connector '{}': {e}", self.id))
+    }
+
+    /// Infer `schema` from the JSON types of `default_params`.
+    ///
+    /// Integers map to `"integer"`, other numbers to `"number"`.
+    pub fn set_schema_from_params(&mut self) {
+        let props: Map<String, JsonValue> = self
+            .default_params
+            .iter()
+            .map(|(k, v)| (k.clone(), json!({ "type": json_schema_type(v) })))
+            .collect();
+        self.schema = Some(json!({ "type": "object", "properties": props }));
+    }
 }
 
+fn json_schema_type(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+/// Wildcard entry for `allow_override` meaning any parameter may be overridden.
+pub const OVERRIDE_ANY: &str = "*";
+
 /// Trait for connectors that can act as a data source.
 ///
 /// Implement this trait to provide source connector metadata and validation.
@@ -66,6 +188,7 @@ impl ConnectorDef {
 ///             allow_override: vec!["batch_size".into()],
 ///             default_params: Default::default(),
 ///             origin: None,
+///             schema: None,
 ///         }
 ///     }
 /// }
@@ -74,6 +197,14 @@ pub trait SourceDefProvider: Send + Sync + 'static {
     /// Returns the connector definition for source mode.
     fn source_def(&self) -> ConnectorDef;
 
+    /// Scope this provider operates in.
+    ///
+    /// Returns [`ConnectorScope::Source`] by default; connectors implementing both
+    /// provider traits can override it to return [`ConnectorScope::Both`].
+    fn scope(&self) -> ConnectorScope {
+        ConnectorScope::Source
+    }
+
     /// Validates a source connector definition.
     ///
     /// Override to add custom validation logic. Returns `Ok(())` by default.
@@ -98,6 +229,7 @@ pub trait SourceDefProvider: Send + Sync + 'static {
 ///             allow_override: vec![],
 ///             default_params: Default::default(),
 ///             origin: None,
+///             schema: None,
 ///         }
 ///     }
 /// }
@@ -106,6 +238,14 @@ pub trait SinkDefProvider: Send + Sync + 'static {
     /// Returns the connector definition for sink mode.
     fn sink_def(&self) -> ConnectorDef;
 
+    /// Scope this provider operates in.
+    ///
+    /// Returns [`ConnectorScope::Sink`] by default; connectors implementing both
+    /// provider traits can override it to return [`ConnectorScope::Both`].
+    fn scope(&self) -> ConnectorScope {
+        ConnectorScope::Sink
+    }
+
     /// Validates a sink connector definition.
     ///
     /// Override to add custom validation logic. Returns `Ok(())` by default.
@@ -138,6 +278,102 @@ mod tests {
         assert_eq!(def.scope, ConnectorScope::Sink);
     }
 
+    fn def_with_overrides(allow: &[&str]) -> ConnectorDef {
+        let mut default_params = ParamMap::new();
+        default_params.insert("host".into(), serde_json::json!("localhost"));
+        default_params.insert("port".into(), serde_json::json!(3306));
+        ConnectorDef {
+            id: "mysql".into(),
+            kind: "mysql".into(),
+            scope: ConnectorScope::Source,
+            allow_override: allow.iter().map(|s| s.to_string()).collect(),
+            default_params,
+            origin: None,
+            schema: None,
+        }
+    }
+
+    #[test]
+    fn test_allow_override_wildcard() {
+        let def = def_with_overrides(&["*"]);
+        assert!(def.is_fully_open());
+        assert!(def.allows_override_of("port"));
+        assert!(def.allows_override_of("anything"));
+
+        let mut overrides = ParamMap::new();
+        overrides.insert("port".into(), serde_json::json!(3307));
+        overrides.insert("extra".into(), serde_json::json!(true));
+        let params = def.resolve_params(&overrides).unwrap();
+        assert_eq!(params.get("port").unwrap(), 3307);
+        assert_eq!(params.get("host").unwrap(), "localhost");
+        assert_eq!(params.get("extra").unwrap(), true);
+    }
+
+    #[test]
+    fn test_allow_override_specific_keys() {
+        let def = def_with_overrides(&["host"]);
+        assert!(!def.is_fully_open());
+        assert!(def.allows_override_of("host"));
+        assert!(!def.allows_override_of("port"));
+
+        let mut overrides = ParamMap::new();
+        overrides.insert("port".into(), serde_json::json!(3307));
+        let err = def.validate_overrides(&overrides).unwrap_err();
+        assert!(err.contains("port"));
+        assert!(def.resolve_params(&overrides).is_err());
+
+        // Empty allow-list rejects every override; empty overrides always pass
+        let closed = def_with_overrides(&[]);
+        assert!(!closed.allows_override_of("host"));
+        assert!(closed.validate_overrides(&ParamMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_params_against_schema() {
+        let mut def = def_with_overrides(&["*"]);
+        assert!(def.validate_params_against_schema(&ParamMap::new()).is_ok());
+
+        def.schema = Some(serde_json::json!({
+            "type": "object",
+            "properties": {"port": {"type": "integer"}, "host": {"type": "string"}}
+        }));
+        let mut params = ParamMap::new();
+        params.insert("port".into(), serde_json::json!("8080"));
+        params.insert("extra".into(), serde_json::json!([1]));
+        let errors = def.validate_params_against_schema(&params).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["$.port: expected integer, got string".to_string()]
+        );
+
+        params.insert("port".into(), serde_json::json!(8080));
+        assert!(def.validate_params_against_schema(&params).is_ok());
+    }
+
+    #[test]
+    fn test_set_schema_from_params_roundtrip() {
+        let mut def = def_with_overrides(&[]);
+        def.default_params
+            .insert("ratio".into(), serde_json::json!(0.5));
+        def.set_schema_from_params();
+        let props = &def.schema.as_ref().unwrap()["properties"];
+        assert_eq!(props["host"]["type"], "string");
+        assert_eq!(props["port"]["type"], "integer");
+        assert_eq!(props["ratio"]["type"], "number");
+        assert!(
+            def.validate_params_against_schema(&def.default_params)
+                .is_ok()
+        );
+
+        let json = serde_json::to_string(&def).unwrap();
+        let back: ConnectorDef = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.schema, def.schema);
+
+        def.schema = None;
+        let json = serde_json::to_string(&def).unwrap();
+        assert!(!json.contains("schema"));
+    }
+
     // Test that SourceDefProvider can be implemented independently
     struct SourceOnlyConnector;
 
@@ -150,6 +386,7 @@ mod tests {
                 allow_override: vec![],
                 default_params: Default::default(),
                 origin: None,
+                schema: None,
             }
         }
     }
@@ -177,6 +414,7 @@ mod tests {
                 allow_override: vec![],
                 default_params: Default::default(),
                 origin: None,
+                schema: None,
             }
         }
     }
@@ -191,4 +429,63 @@ mod tests {
         // validate_sink has default implementation
         assert!(connector.validate_sink(&def).is_ok());
     }
+
+    #[test]
+    fn test_scope_is_source_is_sink() {
+        assert!(ConnectorScope::Source.is_source());
+        assert!(!ConnectorScope::Source.is_sink());
+        assert!(!ConnectorScope::Sink.is_source());
+        assert!(ConnectorScope::Sink.is_sink());
+        assert!(ConnectorScope::Both.is_source());
+        assert!(ConnectorScope::Both.is_sink());
+    }
+
+    // A connector that can be used in both directions
+    struct ChannelConnector;
+
+    impl SourceDefProvider for ChannelConnector {
+        fn source_def(&self) -> ConnectorDef {
+            self.sink_def().with_scope(ConnectorScope::Both)
+        }
+
+        fn scope(&self) -> ConnectorScope {
+            ConnectorScope::Both
+        }
+    }
+
+    impl SinkDefProvider for ChannelConnector {
+        fn sink_def(&self) -> ConnectorDef {
+            ConnectorDef {
+                id: "channel".into(),
+                kind: "channel".into(),
+                scope: ConnectorScope::Both,
+                allow_override: vec![],
+                default_params: Default::default(),
+                origin: None,
+                schema: None,
+            }
+        }
+
+        fn scope(&self) -> ConnectorScope {
+            ConnectorScope::Both
+        }
+    }
+
+    #[test]
+    fn test_provider_scope_defaults_and_both() {
+        assert_eq!(
+            SourceDefProvider::scope(&SourceOnlyConnector),
+            ConnectorScope::Source
+        );
+        assert_eq!(
+            SinkDefProvider::scope(&SinkOnlyConnector),
+            ConnectorScope::Sink
+        );
+
+        let channel = ChannelConnector;
+        assert_eq!(SourceDefProvider::scope(&channel), ConnectorScope::Both);
+        assert_eq!(SinkDefProvider::scope(&channel), ConnectorScope::Both);
+        let def = channel.source_def();
+        assert!(def.scope.is_source() && def.scope.is_sink());
+    }
 }
@@ -1,3 +1,5 @@
+pub mod bridge;
 pub mod cnn;
+pub mod pipeline;
 pub mod sink;
 pub mod source;
@@ -0,0 +1,155 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use super::source::{SourceBatch, SourceEvent};
+
+/// 批次处理阶段：接收整个批次并返回处理后的批次（可增删、改写事件）。
+#[async_trait]
+pub trait EventStage: Send + Sync {
+    async fn process(&self, batch: SourceBatch) -> SourceBatch;
+}
+
+/// 仅保留 `predicate` 返回 `true` 的事件
+pub struct FilterStage {
+    pub predicate: Arc<dyn Fn(&SourceEvent) -> bool + Send + Sync>,
+}
+
+impl FilterStage {
+    pub fn new(predicate: impl Fn(&SourceEvent) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            predicate: Arc::new(predicate),
+        }
+    }
+}
+
+#[async_trait]
+impl EventStage for FilterStage {
+    async fn process(&self, mut batch: SourceBatch) -> SourceBatch {
+        batch.retain(|e| (self.predicate)(e));
+        batch
+    }
+}
+
+/// 对每个事件执行 `transform`
+pub struct MapStage {
+    pub transform: Arc<dyn Fn(SourceEvent) -> SourceEvent + Send + Sync>,
+}
+
+impl MapStage {
+    pub fn new(transform: impl Fn(SourceEvent) -> SourceEvent + Send + Sync + 'static) -> Self {
+        Self {
+            transform: Arc::new(transform),
+        }
+    }
+}
+
+#[async_trait]
+impl EventStage for MapStage {
+    async fn process(&self, batch: SourceBatch) -> SourceBatch {
+        batch.into_iter().map(|e| (self.transform)(e)).collect()
+    }
+}
+
+/// 按添加顺序依次执行各阶段的事件处理流水线；无阶段时原样返回批次。
+#[derive(Default)]
+pub struct EventPipeline {
+    stages: Vec<Box<dyn EventStage>>,
+}
+
+impl std::fmt::Debug for EventPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventPipeline")
+            .field("stages", &self.stages.len())
+            .finish()
+    }
+}
+
+impl EventPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_stage(mut self, stage: impl EventStage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// 追加 [`FilterStage`]
+    pub fn filter(self, predicate: impl Fn(&SourceEvent) -> bool + Send + Sync + 'static) -> Self {
+        self.add_stage(FilterStage::new(predicate))
+    }
+
+    /// 追加 [`MapStage`]
+    pub fn map(
+        self,
+        transform: impl Fn(SourceEvent) -> SourceEvent + Send + Sync + 'static,
+    ) -> Self {
+        self.add_stage(MapStage::new(transform))
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    pub async fn process(&self, mut batch: SourceBatch) -> SourceBatch {
+        for stage in &self.stages {
+            batch = stage.process(batch).await;
+        }
+        batch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::source::Tags;
+    use wp_parse_api::RawData;
+
+    fn batch(n: u64) -> SourceBatch {
+        (0..n)
+            .map(|i| {
+                SourceEvent::new(
+                    i,
+                    "p",
+                    RawData::from_string(format!("e{i}")),
+                    Arc::new(Tags::new()),
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn pipeline_filters_then_maps() {
+        let pipeline = EventPipeline::new()
+            .add_stage(FilterStage::new(|e| e.event_id % 2 != 0))
+            .add_stage(MapStage::new(|mut e| {
+                if let RawData::String(s) = &e.payload {
+                    e.payload = RawData::from_string(format!("processed:{s}"));
+                }
+                e
+            }));
+        assert_eq!(pipeline.len(), 2);
+
+        let out = pipeline.process(batch(4)).await;
+        let payloads: Vec<String> = out.iter().map(|e| e.payload.to_string()).collect();
+        assert_eq!(payloads, vec!["processed:e1", "processed:e3"]);
+    }
+
+    #[tokio::test]
+    async fn pipeline_chained_helpers_and_empty() {
+        let empty = EventPipeline::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.process(batch(3)).await.len(), 3);
+
+        let pipeline = EventPipeline::new()
+            .map(|e| e.with_weight(5))
+            .filter(|e| e.event_id > 0);
+        let out = pipeline.process(batch(3)).await;
+        assert_eq!(out.len(), 2);
+        assert!(out.iter().all(|e| e.weight == 5));
+    }
+}
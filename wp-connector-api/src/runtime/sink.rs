@@ -1,9 +1,17 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 use wp_model_core::model::DataRecord;
 
-use crate::{SinkDefProvider, SinkResult};
+mod fanout;
+mod pool;
+pub use fanout::SinkFanOut;
+pub use pool::{PoolStrategy, SinkPool};
+
+use super::source::Tags;
+use crate::config::param::{parammap_from_env, parammap_merge};
+use crate::types::ParamMap;
+use crate::{ConnectorKindAdapter, SinkDefProvider, SinkReason, SinkResult};
 
 // Reuse workspace error type to avoid duplicating an error abstraction
 
@@ -34,7 +42,8 @@ pub trait AsyncCtrl {
     ///
     /// This method must be idempotent - subsequent calls should return `Ok(())`
     /// without side effects. After calling `stop()`, the sink should not accept
-    /// any more data.
+    /// any more data. Implementations should call [`Self::drain`] with the
+    /// drain budget from [`SinkBuildCtx::drain_timeout`] before closing connections.
     async fn stop(&mut self) -> SinkResult<()>;
 
     /// Reconnect or refresh the sink's underlying connection.
@@ -43,8 +52,41 @@ pub trait AsyncCtrl {
     /// The method should preserve any configuration and state that doesn't
     /// depend on the connection itself.
     async fn reconnect(&mut self) -> SinkResult<()>;
+
+    /// Flush buffered data to the destination. Defaults to a no-op.
+    ///
+    /// The orchestrator calls this before checkpointing; unlike [`Self::stop`]
+    /// the sink keeps accepting data afterwards.
+    async fn flush(&mut self) -> SinkResult<()> {
+        Ok(())
+    }
+
+    /// Liveness probe. Defaults to `Ok(())`.
+    ///
+    /// Must not block: implementations should answer from cached connection
+    /// state and complete in well under 100 ms.
+    async fn health_check(&self) -> SinkResult<()> {
+        Ok(())
+    }
+
+    /// Flush in-flight data within `timeout`.
+    ///
+    /// Returns the number of items left unwritten (`0` on success or when
+    /// unknown). A flush that exceeds the budget returns `SinkReason::Timeout`.
+    async fn drain(&mut self, timeout: Duration) -> SinkResult<usize> {
+        match tokio::time::timeout(timeout, self.flush()).await {
+            Ok(res) => res.map(|_| 0),
+            Err(_) => Err(SinkReason::Timeout(timeout).into()),
+        }
+    }
 }
 
+/// Opaque write confirmation returned by ack-aware sink methods.
+///
+/// Callers downcast it to the concrete type the sink documents (e.g. an
+/// offset or transaction id) to correlate completions with source `AckToken`s.
+pub type SinkAckToken = Arc<dyn std::any::Any + Send + Sync>;
+
 /// Trait for sinking structured records.
 ///
 /// Provides methods for writing parsed, typed data records to a destination.
@@ -65,6 +107,27 @@ pub trait AsyncRecordSink {
     /// # Arguments
     /// * `data` - Vector of records wrapped in Arc for shared ownership
     async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()>;
+
+    /// Write a single record and return a confirmation token.
+    ///
+    /// Defaults to [`Self::sink_record`] followed by a unit `()` token.
+    async fn sink_record_with_ack(&mut self, data: &DataRecord) -> SinkResult<SinkAckToken> {
+        self.sink_record(data).await?;
+        Ok(Arc::new(()))
+    }
+
+    /// Write records one by one via [`Self::sink_record_with_ack`], returning
+    /// one token per record in input order. Stops at the first error.
+    async fn sink_batch_with_acks(
+        &mut self,
+        data: Vec<Arc<DataRecord>>,
+    ) -> SinkResult<Vec<SinkAckToken>> {
+        let mut tokens = Vec::with_capacity(data.len());
+        for record in &data {
+            tokens.push(self.sink_record_with_ack(record).await?);
+        }
+        Ok(tokens)
+    }
 }
 
 /// Trait for sinking raw data (strings and bytes).
@@ -104,11 +167,14 @@ impl<T> AsyncSink for T where T: AsyncRecordSink + AsyncRawDataSink + AsyncCtrl
 
 // ---------- Build Ctx ----------
 
+/// Default budget for [`AsyncCtrl::drain`] when stopping a sink.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
 /// Build context passed to sink factories during construction.
 ///
 /// Contains runtime configuration such as work directories, replica info,
 /// and rate limiting hints that sinks may use during initialization.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct SinkBuildCtx {
     /// Root directory for sink-specific working files (state, checkpoints, etc.)
     pub work_root: PathBuf,
@@ -118,16 +184,35 @@ pub struct SinkBuildCtx {
     pub replica_cnt: usize,
     /// Upstream rate limit hint in requests per second. 0 means unlimited.
     pub rate_limit_rps: usize,
+    /// Prefix stripped from environment variable names when injecting secrets.
+    pub env_prefix: Option<String>,
+    /// Parameters read from the environment (secrets); applied on top of spec params.
+    pub env_overrides: ParamMap,
+    /// Budget for flushing in-flight data on stop. Defaults to [`DEFAULT_DRAIN_TIMEOUT`].
+    pub drain_timeout: Duration,
+}
+
+impl std::fmt::Debug for SinkBuildCtx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Only expose override keys; values may carry secrets
+        f.debug_struct("SinkBuildCtx")
+            .field("work_root", &self.work_root)
+            .field("replica_idx", &self.replica_idx)
+            .field("replica_cnt", &self.replica_cnt)
+            .field("rate_limit_rps", &self.rate_limit_rps)
+            .field("env_prefix", &self.env_prefix)
+            .field(
+                "env_overrides",
+                &self.env_overrides.keys().collect::<Vec<_>>(),
+            )
+            .field("drain_timeout", &self.drain_timeout)
+            .finish()
+    }
 }
 
 impl SinkBuildCtx {
     pub fn new(work_root: PathBuf) -> Self {
-        Self {
-            work_root,
-            replica_idx: 0,
-            replica_cnt: 1,
-            rate_limit_rps: 0,
-        }
+        Self::new_with_replica(work_root, 0, 1)
     }
     pub fn new_with_replica(work_root: PathBuf, replica_idx: usize, replica_cnt: usize) -> Self {
         Self {
@@ -135,12 +220,43 @@ impl SinkBuildCtx {
             replica_idx,
             replica_cnt: replica_cnt.max(1),
             rate_limit_rps: 0,
+            env_prefix: None,
+            env_overrides: ParamMap::new(),
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
         }
     }
     pub fn with_limit(mut self, rate_limit_rps: usize) -> Self {
         self.rate_limit_rps = rate_limit_rps;
         self
     }
+
+    /// Configure the per-sink drain budget used on stop.
+    pub fn set_drain_timeout(&mut self, d: Duration) {
+        self.drain_timeout = d;
+    }
+
+    /// Set the prefix stripped from variable names by [`Self::with_env_secrets`].
+    pub fn with_env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Read the listed environment variables into `env_overrides`.
+    ///
+    /// Keys are lowercased with the configured prefix removed, e.g. `KAFKA_PASSWORD`
+    /// becomes `kafka_password`. Unset variables are skipped.
+    pub fn with_env_secrets(mut self, env_keys: Vec<String>) -> Self {
+        let secrets = parammap_from_env(self.env_prefix.as_deref(), &env_keys);
+        self.env_overrides.extend(secrets);
+        self
+    }
+
+    /// Apply `env_overrides` on top of `params`; environment values win.
+    pub fn merge_with_env(&self, params: &mut ParamMap) {
+        for (k, v) in &self.env_overrides {
+            params.insert(k.clone(), v.clone());
+        }
+    }
 }
 
 /// Handle wrapping a boxed async sink instance.
@@ -150,6 +266,8 @@ impl SinkBuildCtx {
 pub struct SinkHandle {
     /// The boxed sink implementing [`AsyncSink`]
     pub sink: Box<dyn AsyncSink + 'static>,
+    /// Optional metadata for UI/monitoring, mirroring `SourceMeta`; also holds the handle name
+    pub meta: Option<SinkMeta>,
 }
 
 impl std::fmt::Debug for SinkHandle {
@@ -157,13 +275,77 @@ impl std::fmt::Debug for SinkHandle {
         // Name matches the type to avoid confusion in logs/diagnostics
         f.debug_struct("SinkHandle")
             .field("sink", &"Box<dyn AsyncSink>")
+            .field("meta", &self.meta)
             .finish()
     }
 }
 
 impl SinkHandle {
     pub fn new(sink: Box<dyn AsyncSink + 'static>) -> Self {
-        Self { sink }
+        Self { sink, meta: None }
+    }
+
+    /// Set the handle name, stored in `meta.name` like `SourceHandle`.
+    ///
+    /// Creates metadata with an empty `kind` when none is attached yet.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        match &mut self.meta {
+            Some(meta) => meta.name = name.into(),
+            None => self.meta = Some(SinkMeta::new(name, "")),
+        }
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.meta.as_ref().map(|m| m.name.as_str())
+    }
+
+    /// Attach UI/monitoring metadata to this handle.
+    pub fn with_meta(mut self, meta: SinkMeta) -> Self {
+        self.meta = Some(meta);
+        self
+    }
+
+    pub fn metadata(&self) -> Option<&SinkMeta> {
+        self.meta.as_ref()
+    }
+}
+
+/// Sink metadata for UI/monitoring, the sink-side counterpart of `SourceMeta`.
+#[derive(Clone, Debug)]
+pub struct SinkMeta {
+    pub name: String,
+    pub kind: String,
+    pub tags: Tags,
+    /// Human-readable name; falls back to `name` when unset
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+}
+
+impl SinkMeta {
+    pub fn new(name: impl Into<String>, kind: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: kind.into(),
+            tags: Tags::default(),
+            display_name: None,
+            description: None,
+        }
+    }
+
+    pub fn with_display_name(mut self, n: impl Into<String>) -> Self {
+        self.display_name = Some(n.into());
+        self
+    }
+
+    pub fn with_description(mut self, d: impl Into<String>) -> Self {
+        self.description = Some(d.into());
+        self
+    }
+
+    /// `display_name` if set, otherwise `name`.
+    pub fn effective_display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
     }
 }
 
@@ -189,6 +371,97 @@ pub struct ResolvedSinkSpec {
     pub filter: Option<String>,
 }
 
+impl ResolvedSinkSpec {
+    /// Set (or overwrite) a single parameter.
+    pub fn with_param(mut self, key: impl Into<String>, val: serde_json::Value) -> Self {
+        self.params.insert(key.into(), val);
+        self
+    }
+
+    /// Merge `extra` into `params`; keys in `extra` win.
+    pub fn with_params(mut self, extra: crate::types::ParamMap) -> Self {
+        self.params.extend(extra);
+        self
+    }
+
+    /// Start a [`SinkSpecBuilder`].
+    pub fn builder() -> SinkSpecBuilder {
+        SinkSpecBuilder::default()
+    }
+
+    /// Remove a parameter if present.
+    pub fn without_param(mut self, key: &str) -> Self {
+        self.params.remove(key);
+        self
+    }
+}
+
+/// Fluent constructor for [`ResolvedSinkSpec`].
+///
+/// Optional fields (`group`, `params`, `filter`) fall back to their defaults;
+/// `build()` rejects an empty `name`, `kind` or `connector_id`.
+#[derive(Debug, Clone, Default)]
+pub struct SinkSpecBuilder {
+    group: String,
+    name: String,
+    kind: String,
+    connector_id: String,
+    params: ParamMap,
+    filter: Option<String>,
+}
+
+impl SinkSpecBuilder {
+    pub fn group(mut self, s: impl Into<String>) -> Self {
+        self.group = s.into();
+        self
+    }
+
+    pub fn name(mut self, s: impl Into<String>) -> Self {
+        self.name = s.into();
+        self
+    }
+
+    pub fn kind(mut self, s: impl Into<String>) -> Self {
+        self.kind = s.into();
+        self
+    }
+
+    pub fn connector_id(mut self, s: impl Into<String>) -> Self {
+        self.connector_id = s.into();
+        self
+    }
+
+    pub fn params(mut self, params: ParamMap) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn filter(mut self, expr: impl Into<String>) -> Self {
+        self.filter = Some(expr.into());
+        self
+    }
+
+    pub fn build(self) -> Result<ResolvedSinkSpec, String> {
+        for (field, val) in [
+            ("name", &self.name),
+            ("kind", &self.kind),
+            ("connector_id", &self.connector_id),
+        ] {
+            if val.is_empty() {
+                return Err(format!("sink spec requires a non-empty '{field}'"));
+            }
+        }
+        Ok(ResolvedSinkSpec {
+            group: self.group,
+            name: self.name,
+            kind: self.kind,
+            connector_id: self.connector_id,
+            params: self.params,
+            filter: self.filter,
+        })
+    }
+}
+
 /// Factory trait for creating sink instances.
 ///
 /// Implementors must also implement [`SinkDefProvider`] to provide
@@ -215,11 +488,85 @@ pub trait SinkFactory: SinkDefProvider + Send + Sync + 'static {
     /// Optional lightweight validation of the sink specification.
     ///
     /// Called before `build()` to catch configuration errors early.
-    /// Default implementation accepts all specifications.
-    fn validate_spec(&self, _spec: &ResolvedSinkSpec) -> SinkResult<()> {
+    /// Default implementation runs [`crate::ConnectorDef::validate_params`] on
+    /// `sink_def().default_params` merged with `spec.params` (env overrides
+    /// are not known yet, so env-only secrets should not be listed as required),
+    /// then [`SinkFactory::validate_spec_with_adapter`] when
+    /// [`SinkFactory::kind_adapter`] returns one.
+    fn validate_spec(&self, spec: &ResolvedSinkSpec) -> SinkResult<()> {
+        let def = self.sink_def();
+        def.validate_params(&parammap_merge(&def.default_params, &spec.params))
+            .map_err(SinkReason::Sink)?;
+        match self.kind_adapter() {
+            Some(adapter) => self.validate_spec_with_adapter(spec, adapter),
+            None => Ok(()),
+        }
+    }
+
+    /// Optional [`ConnectorKindAdapter`] registered for this factory. Defaults to `None`.
+    fn kind_adapter(&self) -> Option<&dyn ConnectorKindAdapter> {
+        None
+    }
+
+    /// Check `adapter.required_params()` against `adapter.defaults()` merged with
+    /// `spec.params`, reporting failures as `SinkReason::Sink`.
+    fn validate_spec_with_adapter(
+        &self,
+        spec: &ResolvedSinkSpec,
+        adapter: &dyn ConnectorKindAdapter,
+    ) -> SinkResult<()> {
+        adapter
+            .validate_params(&parammap_merge(&adapter.defaults(), &spec.params))
+            .map_err(|e| SinkReason::Sink(format!("adapter '{}': {e}", adapter.kind())).into())
+    }
+
+    /// Async validation: runs [`SinkFactory::validate_spec`] and then
+    /// [`SinkFactory::probe_connectivity`], so a spec is only reported valid
+    /// when its destination is reachable.
+    async fn validate_spec_async(&self, spec: &ResolvedSinkSpec) -> SinkResult<()> {
+        self.validate_spec(spec)?;
+        self.probe_connectivity(spec).await
+    }
+
+    /// Optional reachability probe of the destination described by `spec`.
+    ///
+    /// Orchestrators call this during startup and periodically afterwards.
+    /// Default implementation does nothing.
+    async fn probe_connectivity(&self, _spec: &ResolvedSinkSpec) -> SinkResult<()> {
+        Ok(())
+    }
+
+    /// Optional connectivity check (DNS resolve, TCP connect, auth) run before `build()`.
+    ///
+    /// A failure here should stop the orchestrator from calling `build()`.
+    /// Default implementation does nothing.
+    async fn warm_up(&self, _spec: &ResolvedSinkSpec, _ctx: &SinkBuildCtx) -> SinkResult<()> {
         Ok(())
     }
 
+    /// Run [`SinkFactory::warm_up`] bounded by `timeout`.
+    ///
+    /// Returns `SinkReason::Timeout` when the check does not finish in time.
+    async fn warm_up_with_timeout(
+        &self,
+        spec: &ResolvedSinkSpec,
+        ctx: &SinkBuildCtx,
+        timeout: Duration,
+    ) -> SinkResult<()> {
+        match tokio::time::timeout(timeout, self.warm_up(spec, ctx)).await {
+            Ok(res) => res,
+            Err(_) => Err(SinkReason::Timeout(timeout).into()),
+        }
+    }
+
+    /// Effective runtime params: `sink_def().default_params`, then `spec.params`,
+    /// then `ctx.env_overrides` (later layers win). Intended for use in `build()`.
+    fn effective_params(&self, spec: &ResolvedSinkSpec, ctx: &SinkBuildCtx) -> ParamMap {
+        let mut params = parammap_merge(&self.sink_def().default_params, &spec.params);
+        ctx.merge_with_env(&mut params);
+        params
+    }
+
     /// Construct a new sink instance from the given specification.
     ///
     /// # Arguments
@@ -234,7 +581,9 @@ pub trait SinkFactory: SinkDefProvider + Send + Sync + 'static {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{ConnectorDef, ConnectorScope};
     use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::{path::PathBuf, sync::Arc};
     use wp_model_core::model::DataRecord;
 
@@ -300,11 +649,420 @@ mod tests {
         let limited = SinkBuildCtx::new(PathBuf::from("/tmp/work")).with_limit(250);
         assert_eq!(limited.rate_limit_rps, 250);
         assert_eq!(limited.replica_cnt, 1);
+
+        let mut drained = SinkBuildCtx::new(PathBuf::from("/tmp/work"));
+        assert_eq!(drained.drain_timeout, DEFAULT_DRAIN_TIMEOUT);
+        drained.set_drain_timeout(Duration::from_millis(200));
+        assert_eq!(drained.drain_timeout, Duration::from_millis(200));
+    }
+
+    // ========== ack tests ==========
+
+    #[derive(Default)]
+    struct AckSink {
+        written: usize,
+    }
+
+    #[async_trait]
+    impl AsyncRecordSink for AckSink {
+        async fn sink_record(&mut self, _data: &DataRecord) -> SinkResult<()> {
+            self.written += 1;
+            Ok(())
+        }
+
+        async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()> {
+            self.written += data.len();
+            Ok(())
+        }
+
+        async fn sink_record_with_ack(&mut self, data: &DataRecord) -> SinkResult<SinkAckToken> {
+            self.sink_record(data).await?;
+            let id = match data.get_value(wp_model_core::model::data::record::WP_EVENT_ID) {
+                Some(wp_model_core::model::Value::Digit(id)) => *id,
+                _ => return Err(SinkReason::Sink("missing event id".into()).into()),
+            };
+            Ok(Arc::new(id))
+        }
+    }
+
+    fn record_with_id(id: u64) -> Arc<DataRecord> {
+        let mut record = DataRecord::default();
+        record.set_id(id);
+        Arc::new(record)
+    }
+
+    #[tokio::test]
+    async fn sink_batch_with_acks_returns_tokens_in_order() {
+        let mut sink = AckSink::default();
+        let tokens = sink
+            .sink_batch_with_acks(vec![record_with_id(7), record_with_id(9)])
+            .await
+            .unwrap();
+        let ids: Vec<i64> = tokens
+            .iter()
+            .map(|t| *t.downcast_ref::<i64>().unwrap())
+            .collect();
+        assert_eq!(ids, vec![7, 9]);
+        assert_eq!(sink.written, 2);
+
+        let err = sink
+            .sink_batch_with_acks(vec![record_with_id(1), Arc::new(DataRecord::default())])
+            .await;
+        assert!(err.is_err());
+        assert_eq!(sink.written, 4);
+    }
+
+    #[tokio::test]
+    async fn sink_record_with_ack_defaults_to_unit_token() {
+        let mut sink = NoopSink;
+        let token = sink
+            .sink_record_with_ack(&DataRecord::default())
+            .await
+            .unwrap();
+        assert!(token.downcast_ref::<()>().is_some());
+    }
+
+    struct SlowFlushSink {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl AsyncCtrl for SlowFlushSink {
+        async fn stop(&mut self) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn reconnect(&mut self) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> SinkResult<()> {
+            tokio::time::sleep(self.delay).await;
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn flush_and_health_check_default_to_ok() {
+        let mut noop = NoopSink;
+        assert!(noop.flush().await.is_ok());
+        assert!(noop.health_check().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn drain_flushes_within_budget() {
+        let mut noop = NoopSink;
+        assert_eq!(noop.drain(Duration::from_millis(10)).await.unwrap(), 0);
+
+        let mut fast = SlowFlushSink {
+            delay: Duration::from_millis(1),
+        };
+        assert_eq!(fast.drain(Duration::from_secs(1)).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn drain_times_out_when_flush_blocks() {
+        let mut slow = SlowFlushSink {
+            delay: Duration::from_secs(5),
+        };
+        let started = std::time::Instant::now();
+        let err = slow.drain(Duration::from_millis(50)).await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(
+            err.reason(),
+            &SinkReason::Timeout(Duration::from_millis(50))
+        );
+        assert!(err.reason().is_retryable());
+    }
+
+    #[test]
+    fn sink_build_ctx_injects_env_secrets() {
+        unsafe {
+            std::env::set_var("KAFKA_PASSWORD", "s3cret");
+        }
+        let ctx = SinkBuildCtx::new(PathBuf::from("/tmp/work"))
+            .with_env_secrets(vec!["KAFKA_PASSWORD".into()]);
+        assert_eq!(
+            ctx.env_overrides.get("kafka_password"),
+            Some(&serde_json::json!("s3cret"))
+        );
+
+        let mut params = ParamMap::new();
+        params.insert("kafka_password".into(), serde_json::json!("from-config"));
+        params.insert("topic".into(), serde_json::json!("logs"));
+        ctx.merge_with_env(&mut params);
+        assert_eq!(
+            params.get("kafka_password"),
+            Some(&serde_json::json!("s3cret"))
+        );
+        assert_eq!(params.get("topic"), Some(&serde_json::json!("logs")));
+
+        assert!(!format!("{ctx:?}").contains("s3cret"));
+    }
+
+    #[test]
+    fn sink_build_ctx_env_prefix_is_stripped() {
+        unsafe {
+            std::env::set_var("WPSINK_TOKEN", "abc");
+        }
+        let ctx = SinkBuildCtx::new(PathBuf::from("/tmp/work"))
+            .with_env_prefix("WPSINK")
+            .with_env_secrets(vec!["WPSINK_TOKEN".into()]);
+        assert_eq!(
+            ctx.env_overrides.get("token"),
+            Some(&serde_json::json!("abc"))
+        );
+    }
+
+    #[test]
+    fn resolved_sink_spec_param_builders() {
+        let mut extra = crate::types::ParamMap::new();
+        extra.insert("topic".into(), serde_json::json!("logs"));
+        extra.insert("acks".into(), serde_json::json!("all"));
+
+        let spec = ResolvedSinkSpec::default()
+            .with_param("timeout_ms", serde_json::json!(5000))
+            .with_param("acks", serde_json::json!(1))
+            .with_params(extra)
+            .without_param("topic");
+        assert_eq!(spec.params["timeout_ms"], serde_json::json!(5000));
+        assert_eq!(spec.params["acks"], serde_json::json!("all"));
+        assert!(!spec.params.contains_key("topic"));
     }
 
     #[test]
     fn sink_handle_wraps_async_sink() {
         let handle = SinkHandle::new(Box::new(NoopSink));
         assert!(format!("{handle:?}").contains("SinkHandle"));
+        assert_eq!(handle.name(), None);
+        assert!(!format!("{handle:?}").contains("name"));
+    }
+
+    #[test]
+    fn sink_handle_with_name_shows_in_debug() {
+        let handle = SinkHandle::new(Box::new(NoopSink)).with_name("kafka-prod");
+        assert_eq!(handle.name(), Some("kafka-prod"));
+        assert!(format!("{handle:?}").contains(r#"name: "kafka-prod""#));
+    }
+
+    #[test]
+    fn sink_handle_exposes_metadata() {
+        let handle = SinkHandle::new(Box::new(NoopSink));
+        assert!(handle.metadata().is_none());
+
+        let handle = handle.with_meta(SinkMeta::new("kafka-prod", "kafka"));
+        let meta = handle.metadata().unwrap();
+        assert_eq!(meta.kind, "kafka");
+        assert_eq!(meta.effective_display_name(), "kafka-prod");
+        assert_eq!(handle.name(), Some("kafka-prod"));
+        assert!(format!("{handle:?}").contains(r#"kind: "kafka""#));
+
+        let handle = handle.with_name("kafka-dr");
+        assert_eq!(handle.name(), Some("kafka-dr"));
+        assert_eq!(handle.metadata().unwrap().kind, "kafka");
+
+        let meta = SinkMeta::new("kafka-prod", "kafka")
+            .with_display_name("Kafka (prod)")
+            .with_description("primary event bus");
+        assert_eq!(meta.effective_display_name(), "Kafka (prod)");
+        assert_eq!(meta.description.as_deref(), Some("primary event bus"));
+    }
+
+    // ========== warm_up tests ==========
+
+    #[derive(Default)]
+    struct WarmUpFactory {
+        fail: bool,
+        slow: bool,
+        required: &'static [&'static str],
+        adapter: Option<&'static dyn ConnectorKindAdapter>,
+        builds: AtomicUsize,
+    }
+
+    struct HttpAdapter;
+
+    impl ConnectorKindAdapter for HttpAdapter {
+        fn kind(&self) -> &'static str {
+            "http"
+        }
+        fn required_params(&self) -> &[&'static str] {
+            &["endpoint"]
+        }
+    }
+
+    impl SinkDefProvider for WarmUpFactory {
+        fn sink_def(&self) -> ConnectorDef {
+            ConnectorDef {
+                id: "warm".into(),
+                kind: "warm".into(),
+                scope: ConnectorScope::Sink,
+                allow_override: Vec::new(),
+                default_params: ParamMap::new(),
+                origin: None,
+                schema: (!self.required.is_empty())
+                    .then(|| serde_json::json!({ "required": self.required })),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SinkFactory for WarmUpFactory {
+        fn kind(&self) -> &'static str {
+            "warm"
+        }
+
+        fn kind_adapter(&self) -> Option<&dyn ConnectorKindAdapter> {
+            self.adapter
+        }
+
+        async fn warm_up(&self, _spec: &ResolvedSinkSpec, _ctx: &SinkBuildCtx) -> SinkResult<()> {
+            if self.slow {
+                std::future::pending::<()>().await;
+            }
+            if self.fail {
+                return Err(SinkReason::Sink("unreachable".into()).into());
+            }
+            Ok(())
+        }
+
+        async fn build(
+            &self,
+            _spec: &ResolvedSinkSpec,
+            _ctx: &SinkBuildCtx,
+        ) -> SinkResult<SinkHandle> {
+            self.builds.fetch_add(1, Ordering::SeqCst);
+            Ok(SinkHandle::new(Box::new(NoopSink)))
+        }
+    }
+
+    #[test]
+    fn sink_validate_spec_checks_required_params() {
+        let factory = WarmUpFactory {
+            required: &["endpoint"],
+            ..Default::default()
+        };
+        let spec = ResolvedSinkSpec::default();
+        let err = factory.validate_spec(&spec).unwrap_err();
+        match err.reason() {
+            SinkReason::Sink(msg) => assert!(msg.contains("endpoint"), "{msg}"),
+            other => panic!("unexpected reason: {other:?}"),
+        }
+
+        let spec = spec.with_param("endpoint", serde_json::json!("http://x"));
+        assert!(factory.validate_spec(&spec).is_ok());
+        assert!(
+            WarmUpFactory::default()
+                .validate_spec(&ResolvedSinkSpec::default())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn sink_validate_spec_delegates_to_kind_adapter() {
+        let factory = WarmUpFactory {
+            adapter: Some(&HttpAdapter),
+            ..Default::default()
+        };
+        let spec = ResolvedSinkSpec::default();
+        let err = factory.validate_spec(&spec).unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SinkReason::Sink("adapter 'http': missing required params: endpoint".into())
+        );
+
+        let spec = spec.with_param("endpoint", serde_json::json!("http://x"));
+        assert!(factory.validate_spec(&spec).is_ok());
+    }
+
+    async fn warm_then_build(f: &WarmUpFactory) -> SinkResult<SinkHandle> {
+        let spec = ResolvedSinkSpec::default();
+        let ctx = SinkBuildCtx::new(PathBuf::from("/tmp/work"));
+        f.warm_up_with_timeout(&spec, &ctx, Duration::from_millis(50))
+            .await?;
+        f.build(&spec, &ctx).await
+    }
+
+    #[tokio::test]
+    async fn sink_warm_up_failure_skips_build() {
+        let ok = WarmUpFactory::default();
+        assert!(warm_then_build(&ok).await.is_ok());
+        assert_eq!(ok.builds.load(Ordering::SeqCst), 1);
+
+        let failing = WarmUpFactory {
+            fail: true,
+            ..Default::default()
+        };
+        assert!(warm_then_build(&failing).await.is_err());
+        assert_eq!(failing.builds.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn sink_warm_up_times_out() {
+        let slow = WarmUpFactory {
+            slow: true,
+            ..Default::default()
+        };
+        let err = warm_then_build(&slow).await.unwrap_err();
+        assert!(matches!(err.reason(), SinkReason::Timeout(_)));
+        assert_eq!(slow.builds.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn sink_effective_params_layers_spec_and_env() {
+        unsafe {
+            std::env::set_var("WPEFF_SINK_TOPIC", "audit");
+        }
+        let spec = ResolvedSinkSpec::default()
+            .with_param("topic", serde_json::json!("logs"))
+            .with_param("acks", serde_json::json!(1));
+        let ctx = SinkBuildCtx::new(PathBuf::from("/tmp/work"))
+            .with_env_prefix("WPEFF_SINK")
+            .with_env_secrets(vec!["WPEFF_SINK_TOPIC".into()]);
+
+        let params = WarmUpFactory::default().effective_params(&spec, &ctx);
+        assert_eq!(params["topic"], serde_json::json!("audit"));
+        assert_eq!(params["acks"], serde_json::json!(1));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sink_validate_spec_async_defaults_to_ok() {
+        let factory = WarmUpFactory::default();
+        let spec = ResolvedSinkSpec::default();
+        assert!(factory.probe_connectivity(&spec).await.is_ok());
+        assert!(factory.validate_spec_async(&spec).await.is_ok());
+    }
+
+    #[test]
+    fn sink_spec_builder_populates_and_validates() {
+        let mut params = ParamMap::new();
+        params.insert("topic".into(), serde_json::json!("events"));
+        let spec = ResolvedSinkSpec::builder()
+            .group("g1")
+            .name("out")
+            .kind("kafka")
+            .connector_id("kafka-main")
+            .params(params.clone())
+            .filter("level == 'error'")
+            .build()
+            .unwrap();
+        assert_eq!(
+            spec,
+            ResolvedSinkSpec {
+                group: "g1".into(),
+                name: "out".into(),
+                kind: "kafka".into(),
+                connector_id: "kafka-main".into(),
+                params,
+                filter: Some("level == 'error'".into()),
+            }
+        );
+
+        let err = ResolvedSinkSpec::builder()
+            .kind("kafka")
+            .connector_id("kafka-main")
+            .build()
+            .unwrap_err();
+        assert!(err.contains("'name'"));
     }
 }
@@ -0,0 +1,370 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use wp_model_core::model::DataRecord;
+
+use super::{AsyncCtrl, AsyncRawDataSink, AsyncRecordSink, AsyncSink};
+use crate::{SinkError, SinkReason, SinkResult};
+
+/// Writes every record or payload to all inner sinks, in order.
+///
+/// A failing sink does not skip the ones after it: data methods return the
+/// first error once every sink has been attempted, while `stop` / `reconnect`
+/// combine all failures into one error.
+pub struct SinkFanOut {
+    sinks: Vec<Box<dyn AsyncSink + 'static>>,
+}
+
+impl std::fmt::Debug for SinkFanOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SinkFanOut")
+            .field("sinks", &self.sinks.len())
+            .finish()
+    }
+}
+
+impl SinkFanOut {
+    pub fn new(sinks: Vec<Box<dyn AsyncSink + 'static>>) -> Self {
+        Self { sinks }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+}
+
+fn first_error(errors: Vec<SinkError>) -> SinkResult<()> {
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// A single failure is returned as-is. Several failures are folded into one
+/// `SinkReason::Sink`, unless all of them are retryable: then the first error's reason
+/// is kept so `is_retryable()` still holds, with the combined summary as its detail.
+fn aggregate(op: &str, total: usize, errors: Vec<SinkError>) -> SinkResult<()> {
+    if errors.len() <= 1 {
+        return first_error(errors);
+    }
+    let reasons: Vec<String> = errors.iter().map(|e| e.reason().to_string()).collect();
+    let summary = format!(
+        "{op} failed on {} of {total} sinks: {}",
+        errors.len(),
+        reasons.join("; ")
+    );
+    if errors.iter().all(|e| e.reason().is_retryable()) {
+        let first = errors.into_iter().next().expect("at least two errors");
+        return Err(first.with_detail(summary));
+    }
+    Err(SinkReason::Sink(summary).into())
+}
+
+#[async_trait]
+impl AsyncCtrl for SinkFanOut {
+    async fn stop(&mut self) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.stop().await {
+                errors.push(e);
+            }
+        }
+        aggregate("stop", self.sinks.len(), errors)
+    }
+
+    async fn reconnect(&mut self) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.reconnect().await {
+                errors.push(e);
+            }
+        }
+        aggregate("reconnect", self.sinks.len(), errors)
+    }
+
+    async fn flush(&mut self) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.flush().await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+
+    /// Any unhealthy inner sink makes the fan-out unhealthy.
+    async fn health_check(&self) -> SinkResult<()> {
+        for sink in &self.sinks {
+            sink.health_check().await?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncRecordSink for SinkFanOut {
+    async fn sink_record(&mut self, data: &DataRecord) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_record(data).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+
+    async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_records(data.clone()).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+}
+
+#[async_trait]
+impl AsyncRawDataSink for SinkFanOut {
+    async fn sink_str(&mut self, data: &str) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_str(data).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+
+    async fn sink_bytes(&mut self, data: &[u8]) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_bytes(data).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+
+    async fn sink_str_batch(&mut self, data: Vec<&str>) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_str_batch(data.clone()).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+
+    async fn sink_bytes_batch(&mut self, data: Vec<&[u8]>) -> SinkResult<()> {
+        let mut errors = Vec::new();
+        for sink in &mut self.sinks {
+            if let Err(e) = sink.sink_bytes_batch(data.clone()).await {
+                errors.push(e);
+            }
+        }
+        first_error(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Builds the failure reason from the sink name.
+    type FailWith = fn(&str) -> SinkReason;
+
+    /// Records every write; fails all calls with the `fail` reason when set.
+    struct RecordingSink {
+        name: &'static str,
+        fail: Option<FailWith>,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingSink {
+        fn boxed(
+            name: &'static str,
+            fail: bool,
+            log: &Arc<Mutex<Vec<String>>>,
+        ) -> Box<dyn AsyncSink> {
+            let down: FailWith = |name| SinkReason::Sink(format!("{name} down"));
+            Self::failing_with(name, fail.then_some(down), log)
+        }
+
+        fn failing_with(
+            name: &'static str,
+            fail: Option<FailWith>,
+            log: &Arc<Mutex<Vec<String>>>,
+        ) -> Box<dyn AsyncSink> {
+            Box::new(Self {
+                name,
+                fail,
+                log: log.clone(),
+            })
+        }
+
+        fn write(&self, what: String) -> SinkResult<()> {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{}:{what}", self.name));
+            match self.fail {
+                Some(reason) => Err(reason(self.name).into()),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AsyncCtrl for RecordingSink {
+        async fn stop(&mut self) -> SinkResult<()> {
+            self.write("stop".into())
+        }
+
+        async fn reconnect(&mut self) -> SinkResult<()> {
+            self.write("reconnect".into())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncRecordSink for RecordingSink {
+        async fn sink_record(&mut self, _data: &DataRecord) -> SinkResult<()> {
+            self.write("record".into())
+        }
+
+        async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()> {
+            self.write(format!("records({})", data.len()))
+        }
+    }
+
+    #[async_trait]
+    impl AsyncRawDataSink for RecordingSink {
+        async fn sink_str(&mut self, data: &str) -> SinkResult<()> {
+            self.write(format!("str({data})"))
+        }
+
+        async fn sink_bytes(&mut self, data: &[u8]) -> SinkResult<()> {
+            self.write(format!("bytes({})", data.len()))
+        }
+
+        async fn sink_str_batch(&mut self, data: Vec<&str>) -> SinkResult<()> {
+            self.write(format!("strs({})", data.len()))
+        }
+
+        async fn sink_bytes_batch(&mut self, data: Vec<&[u8]>) -> SinkResult<()> {
+            self.write(format!("bytes_batch({})", data.len()))
+        }
+    }
+
+    fn take(log: &Arc<Mutex<Vec<String>>>) -> Vec<String> {
+        std::mem::take(&mut *log.lock().unwrap())
+    }
+
+    #[tokio::test]
+    async fn fan_out_delivers_to_every_sink() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut fan = SinkFanOut::new(vec![
+            RecordingSink::boxed("a", false, &log),
+            RecordingSink::boxed("b", false, &log),
+        ]);
+        assert_eq!(fan.len(), 2);
+
+        fan.sink_record(&DataRecord::default()).await.unwrap();
+        let batch = (0..3).map(|_| Arc::new(DataRecord::default())).collect();
+        fan.sink_records(batch).await.unwrap();
+        fan.sink_str("hi").await.unwrap();
+        fan.sink_bytes_batch(vec![b"x".as_slice(), b"y"])
+            .await
+            .unwrap();
+        assert_eq!(
+            take(&log),
+            vec![
+                "a:record",
+                "b:record",
+                "a:records(3)",
+                "b:records(3)",
+                "a:str(hi)",
+                "b:str(hi)",
+                "a:bytes_batch(2)",
+                "b:bytes_batch(2)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_sink_does_not_skip_later_sinks() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut fan = SinkFanOut::new(vec![
+            RecordingSink::boxed("a", true, &log),
+            RecordingSink::boxed("b", false, &log),
+            RecordingSink::boxed("c", true, &log),
+        ]);
+
+        let err = fan.sink_record(&DataRecord::default()).await.unwrap_err();
+        assert_eq!(err.reason(), &SinkReason::Sink("a down".into()));
+        assert_eq!(take(&log), vec!["a:record", "b:record", "c:record"]);
+
+        let err = fan.sink_bytes(b"raw").await.unwrap_err();
+        assert_eq!(err.reason(), &SinkReason::Sink("a down".into()));
+        assert_eq!(take(&log).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn stop_and_reconnect_aggregate_errors() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut fan = SinkFanOut::new(vec![
+            RecordingSink::boxed("a", true, &log),
+            RecordingSink::boxed("b", false, &log),
+            RecordingSink::boxed("c", true, &log),
+        ]);
+        let err = fan.stop().await.unwrap_err();
+        match err.reason() {
+            SinkReason::Sink(msg) => {
+                assert!(msg.starts_with("stop failed on 2 of 3 sinks"));
+                assert!(msg.contains("a down") && msg.contains("c down"));
+            }
+            other => panic!("unexpected reason: {other:?}"),
+        }
+        assert_eq!(take(&log), vec!["a:stop", "b:stop", "c:stop"]);
+
+        let mut single = SinkFanOut::new(vec![
+            RecordingSink::boxed("a", false, &log),
+            RecordingSink::boxed("b", true, &log),
+        ]);
+        let err = single.reconnect().await.unwrap_err();
+        assert_eq!(err.reason(), &SinkReason::Sink("b down".into()));
+
+        let mut retryable = SinkFanOut::new(vec![
+            RecordingSink::failing_with("a", Some(|_| SinkReason::Throttled), &log),
+            RecordingSink::failing_with(
+                "b",
+                Some(|_| SinkReason::Timeout(std::time::Duration::from_secs(1))),
+                &log,
+            ),
+        ]);
+        let err = retryable.stop().await.unwrap_err();
+        assert_eq!(err.reason(), &SinkReason::Throttled);
+        assert!(err.reason().is_retryable());
+        let detail = err.detail().clone().unwrap();
+        assert!(
+            detail.starts_with("stop failed on 2 of 2 sinks"),
+            "{detail}"
+        );
+
+        let mut mixed = SinkFanOut::new(vec![
+            RecordingSink::failing_with("a", Some(|_| SinkReason::Throttled), &log),
+            RecordingSink::boxed("b", true, &log),
+        ]);
+        let err = mixed.reconnect().await.unwrap_err();
+        assert!(!err.reason().is_retryable());
+
+        let mut empty = SinkFanOut::new(Vec::new());
+        assert!(empty.is_empty());
+        assert!(empty.stop().await.is_ok());
+        assert!(empty.sink_str("x").await.is_ok());
+    }
+}
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use wp_model_core::model::DataRecord;
+
+use super::{AsyncRecordSink, DEFAULT_DRAIN_TIMEOUT, SinkAckToken, SinkHandle};
+use crate::{SinkReason, SinkResult};
+
+/// Strategy used by [`SinkPool`] to pick the handle for the next write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PoolStrategy {
+    /// Cycle through handles in order.
+    #[default]
+    RoundRobin,
+}
+
+/// A group of sink handles that spreads record writes across its members.
+///
+/// Each write (single record or batch) goes to exactly one handle chosen by
+/// the configured [`PoolStrategy`].
+pub struct SinkPool {
+    handles: Vec<SinkHandle>,
+    cursor: usize,
+    strategy: PoolStrategy,
+}
+
+impl std::fmt::Debug for SinkPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SinkPool")
+            .field("handles", &self.handles)
+            .field("strategy", &self.strategy)
+            .finish()
+    }
+}
+
+impl SinkPool {
+    pub fn new(handles: Vec<SinkHandle>, strategy: PoolStrategy) -> Self {
+        Self {
+            handles,
+            cursor: 0,
+            strategy,
+        }
+    }
+
+    pub fn strategy(&self) -> PoolStrategy {
+        self.strategy
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Drain every handle with [`DEFAULT_DRAIN_TIMEOUT`], returning one result per handle
+    /// in pool order. A failing handle does not stop the remaining drains.
+    pub async fn drain_all(&mut self) -> Vec<SinkResult<()>> {
+        let mut results = Vec::with_capacity(self.handles.len());
+        for handle in &mut self.handles {
+            results.push(handle.sink.drain(DEFAULT_DRAIN_TIMEOUT).await.map(|_| ()));
+        }
+        results
+    }
+
+    fn select(&mut self) -> SinkResult<usize> {
+        let n = self.handles.len();
+        if n == 0 {
+            return Err(SinkReason::Sink("sink pool is empty".into()).into());
+        }
+        let idx = match self.strategy {
+            PoolStrategy::RoundRobin => self.cursor % n,
+        };
+        self.cursor = self.cursor.wrapping_add(1);
+        Ok(idx)
+    }
+}
+
+#[async_trait]
+impl AsyncRecordSink for SinkPool {
+    async fn sink_record(&mut self, data: &DataRecord) -> SinkResult<()> {
+        let idx = self.select()?;
+        self.handles[idx].sink.sink_record(data).await
+    }
+
+    async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()> {
+        let idx = self.select()?;
+        self.handles[idx].sink.sink_records(data).await
+    }
+
+    async fn sink_record_with_ack(&mut self, data: &DataRecord) -> SinkResult<SinkAckToken> {
+        let idx = self.select()?;
+        self.handles[idx].sink.sink_record_with_ack(data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AsyncCtrl, AsyncRawDataSink};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingSink {
+        records: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl AsyncCtrl for CountingSink {
+        async fn stop(&mut self) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn reconnect(&mut self) -> SinkResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncRecordSink for CountingSink {
+        async fn sink_record(&mut self, _data: &DataRecord) -> SinkResult<()> {
+            self.records.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn sink_records(&mut self, data: Vec<Arc<DataRecord>>) -> SinkResult<()> {
+            self.records.fetch_add(data.len(), Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncRawDataSink for CountingSink {
+        async fn sink_str(&mut self, _data: &str) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn sink_bytes(&mut self, _data: &[u8]) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn sink_str_batch(&mut self, _data: Vec<&str>) -> SinkResult<()> {
+            Ok(())
+        }
+
+        async fn sink_bytes_batch(&mut self, _data: Vec<&[u8]>) -> SinkResult<()> {
+            Ok(())
+        }
+    }
+
+    fn pool(strategy: PoolStrategy) -> (SinkPool, Vec<Arc<AtomicUsize>>) {
+        let counters: Vec<Arc<AtomicUsize>> =
+            (0..3).map(|_| Arc::new(AtomicUsize::new(0))).collect();
+        let handles = counters
+            .iter()
+            .map(|c| SinkHandle::new(Box::new(CountingSink { records: c.clone() })))
+            .collect();
+        (SinkPool::new(handles, strategy), counters)
+    }
+
+    fn counts(counters: &[Arc<AtomicUsize>]) -> Vec<usize> {
+        counters.iter().map(|c| c.load(Ordering::SeqCst)).collect()
+    }
+
+    #[tokio::test]
+    async fn round_robin_pool_spreads_records_evenly() {
+        let (mut pool, counters) = pool(PoolStrategy::RoundRobin);
+        let record = DataRecord::default();
+        for _ in 0..9 {
+            pool.sink_record(&record).await.unwrap();
+        }
+        assert_eq!(counts(&counters), vec![3, 3, 3]);
+
+        let batch = (0..4).map(|_| Arc::new(record.clone())).collect();
+        pool.sink_records(batch).await.unwrap();
+        assert_eq!(counts(&counters), vec![7, 3, 3]);
+    }
+
+    #[tokio::test]
+    async fn drain_all_and_empty_pool() {
+        let (mut pool, _) = pool(PoolStrategy::RoundRobin);
+        let results = pool.drain_all().await;
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.is_ok()));
+
+        let mut empty = SinkPool::new(Vec::new(), PoolStrategy::default());
+        assert!(empty.is_empty());
+        assert!(empty.sink_record(&DataRecord::default()).await.is_err());
+        assert!(empty.drain_all().await.is_empty());
+    }
+}
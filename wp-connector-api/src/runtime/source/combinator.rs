@@ -0,0 +1,372 @@
+use async_trait::async_trait;
+
+use super::event::SourceBatch;
+use super::types::{ControlEvent, CtrlRx, DataSource, SeekPosition, SourceCaps};
+use crate::{SourceReason, SourceResult};
+use std::sync::Arc;
+
+/// 将多个 `DataSource` 合并为一个，按轮询顺序拉取。
+///
+/// 某个内部源返回 `SourceReason::EOF` 时将其移出轮询，全部耗尽后才返回 `EOF`；
+/// 其他错误原样返回，下次调用从下一个源开始，持续出错的源不会独占轮询。
+///
+/// 注意：`receive()` 每次只 await 轮到的那一个内部源，不会并发等待；若该源一直没有数据
+/// （`receive()` 阻塞），其余源也会被一并阻塞。`DataSource::receive` 不保证取消安全，
+/// 并发等待后丢弃未完成的 future 可能丢数据，因此这里不做并发。内部源在无数据时应尽快返回空批次。
+pub struct MergedSource {
+    sources: Vec<Box<dyn DataSource>>,
+    next: usize,
+    /// 构造时的内部源数量，用于稳定的 `identifier()`
+    total: usize,
+}
+
+impl MergedSource {
+    pub fn new(sources: Vec<Box<dyn DataSource>>) -> Self {
+        let total = sources.len();
+        Self {
+            sources,
+            next: 0,
+            total,
+        }
+    }
+
+    /// 当前仍在轮询中的内部源数量
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+#[async_trait]
+impl DataSource for MergedSource {
+    async fn receive(&mut self) -> SourceResult<SourceBatch> {
+        while !self.sources.is_empty() {
+            let idx = self.next % self.sources.len();
+            match self.sources[idx].receive().await {
+                Ok(batch) => {
+                    self.next = idx + 1;
+                    return Ok(batch);
+                }
+                Err(e) if matches!(e.reason(), SourceReason::EOF) => {
+                    let mut done = self.sources.remove(idx);
+                    let _ = done.close().await;
+                    self.next = idx;
+                }
+                Err(e) => {
+                    self.next = idx + 1;
+                    return Err(e);
+                }
+            }
+        }
+        Err(SourceReason::EOF.into())
+    }
+
+    fn try_receive(&mut self) -> Option<SourceBatch> {
+        let len = self.sources.len();
+        for step in 0..len {
+            let idx = (self.next + step) % len;
+            let src = &mut self.sources[idx];
+            if !src.can_try_receive() {
+                continue;
+            }
+            if let Some(batch) = src.try_receive() {
+                self.next = idx + 1;
+                return Some(batch);
+            }
+        }
+        None
+    }
+
+    fn supports_try_receive(&self) -> bool {
+        self.sources.iter().any(|s| s.supports_try_receive())
+    }
+
+    fn can_try_receive(&mut self) -> bool {
+        self.sources.iter_mut().any(|s| s.can_try_receive())
+    }
+
+    /// 按构造时的内部源数量生成，内部源耗尽后保持不变。
+    fn identifier(&self) -> String {
+        format!("merged:[{}]", self.total)
+    }
+
+    /// 各能力取交集；ack token 无法路由回来源，因此 `ack` 恒为 false。
+    fn caps(&self) -> SourceCaps {
+        let mut caps = SourceCaps {
+            ack: false,
+            seek: !self.sources.is_empty(),
+            parallel: !self.sources.is_empty(),
+        };
+        for src in &self.sources {
+            let inner = src.caps();
+            caps.seek &= inner.seek;
+            caps.parallel &= inner.parallel;
+        }
+        caps
+    }
+
+    async fn start(&mut self, ctrl_rx: CtrlRx) -> SourceResult<()> {
+        for src in &mut self.sources {
+            src.start(ctrl_rx.clone()).await?;
+        }
+        Ok(())
+    }
+
+    async fn close(&mut self) -> SourceResult<()> {
+        // 关闭全部内部源，返回第一个错误
+        let mut first_err = None;
+        for src in &mut self.sources {
+            if let Err(e) = src.close().await {
+                first_err.get_or_insert(e);
+            }
+        }
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    async fn flush(&mut self) -> SourceResult<()> {
+        for src in &mut self.sources {
+            src.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// 任一内部源不健康即返回其错误
+    async fn health_check(&self) -> SourceResult<()> {
+        for src in &self.sources {
+            src.health_check().await?;
+        }
+        Ok(())
+    }
+
+    async fn seek(&mut self, pos: Arc<dyn SeekPosition>) -> SourceResult<()> {
+        for src in &mut self.sources {
+            src.seek(pos.clone()).await?;
+        }
+        Ok(())
+    }
+
+    async fn handle_control(&mut self, event: ControlEvent) -> SourceResult<()> {
+        for src in &mut self.sources {
+            src.handle_control(event.clone()).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::source::{SourceEvent, Tags};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wp_parse_api::RawData;
+
+    struct CountingSource {
+        name: &'static str,
+        remaining: usize,
+        caps: SourceCaps,
+    }
+
+    impl CountingSource {
+        fn boxed(name: &'static str, remaining: usize) -> Box<dyn DataSource> {
+            Box::new(Self {
+                name,
+                remaining,
+                caps: SourceCaps::default(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl DataSource for CountingSource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            if self.remaining == 0 {
+                return Err(SourceReason::EOF.into());
+            }
+            self.remaining -= 1;
+            Ok(vec![SourceEvent::new(
+                self.remaining as u64,
+                self.name,
+                RawData::from_string(self.name),
+                Arc::new(Tags::new()),
+            )])
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            self.name.to_string()
+        }
+
+        fn caps(&self) -> SourceCaps {
+            self.caps
+        }
+
+        async fn health_check(&self) -> SourceResult<()> {
+            if self.remaining == 0 {
+                return Err(SourceReason::Disconnect(format!("{} exhausted", self.name)).into());
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn merged_source_round_robin_until_all_eof() {
+        let mut merged = MergedSource::new(vec![
+            CountingSource::boxed("a", 1),
+            CountingSource::boxed("b", 3),
+        ]);
+        assert_eq!(merged.identifier(), "merged:[2]");
+
+        let mut keys = Vec::new();
+        loop {
+            match merged.receive().await {
+                Ok(batch) => keys.extend(batch.into_iter().map(|e| e.src_key.to_string())),
+                Err(e) => {
+                    assert!(matches!(e.reason(), SourceReason::EOF));
+                    break;
+                }
+            }
+        }
+        assert_eq!(keys, vec!["a", "b", "b", "b"]);
+        assert!(merged.is_empty());
+        assert_eq!(merged.identifier(), "merged:[2]");
+    }
+
+    #[tokio::test]
+    async fn merged_source_forwards_flush_and_health_check() {
+        let mut healthy = MergedSource::new(vec![
+            CountingSource::boxed("a", 1),
+            CountingSource::boxed("b", 2),
+        ]);
+        assert!(healthy.flush().await.is_ok());
+        assert!(healthy.health_check().await.is_ok());
+
+        let degraded = MergedSource::new(vec![
+            CountingSource::boxed("a", 1),
+            CountingSource::boxed("b", 0),
+        ]);
+        let err = degraded.health_check().await.unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SourceReason::Disconnect("b exhausted".into())
+        );
+    }
+
+    /// 每次 `receive` 都返回 `Disconnect`，模拟重连中的源
+    struct FailingSource;
+
+    #[async_trait]
+    impl DataSource for FailingSource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            Err(SourceReason::Disconnect("reconnecting".into()).into())
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            "failing".to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn merged_source_failing_source_does_not_starve_others() {
+        let mut merged =
+            MergedSource::new(vec![Box::new(FailingSource), CountingSource::boxed("b", 2)]);
+
+        let mut keys = Vec::new();
+        for _ in 0..4 {
+            match merged.receive().await {
+                Ok(batch) => keys.extend(batch.into_iter().map(|e| e.src_key.to_string())),
+                Err(e) => assert_eq!(e.reason(), &SourceReason::Disconnect("reconnecting".into())),
+            }
+        }
+        assert_eq!(keys, vec!["b", "b"]);
+    }
+
+    struct LifecycleSource {
+        starts: Arc<AtomicUsize>,
+        closes: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl DataSource for LifecycleSource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            Err(SourceReason::EOF.into())
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            "lifecycle".to_string()
+        }
+
+        async fn start(&mut self, _ctrl_rx: CtrlRx) -> SourceResult<()> {
+            self.starts.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn close(&mut self) -> SourceResult<()> {
+            self.closes.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn merged_source_forwards_start_and_close() {
+        let starts = Arc::new(AtomicUsize::new(0));
+        let closes = Arc::new(AtomicUsize::new(0));
+        let inner = |_| -> Box<dyn DataSource> {
+            Box::new(LifecycleSource {
+                starts: starts.clone(),
+                closes: closes.clone(),
+            })
+        };
+        let mut merged = MergedSource::new((0..3).map(inner).collect());
+
+        let (_tx, rx) = async_broadcast::broadcast(4);
+        merged.start(rx).await.unwrap();
+        assert_eq!(starts.load(Ordering::SeqCst), 3);
+        merged.close().await.unwrap();
+        assert_eq!(closes.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn merged_source_caps_intersection() {
+        let full = SourceCaps {
+            ack: true,
+            seek: true,
+            parallel: true,
+        };
+        let merged = MergedSource::new(vec![
+            Box::new(CountingSource {
+                name: "a",
+                remaining: 0,
+                caps: full,
+            }),
+            Box::new(CountingSource {
+                name: "b",
+                remaining: 0,
+                caps: SourceCaps {
+                    parallel: false,
+                    ..full
+                },
+            }),
+        ]);
+        let caps = merged.caps();
+        assert!(caps.seek);
+        assert!(!caps.parallel);
+        assert!(!caps.ack);
+    }
+}
@@ -1,6 +1,8 @@
 use smol_str::SmolStr;
+use std::collections::{HashMap, HashSet};
 use std::net::IpAddr;
 use std::sync::Arc;
+use std::time::Instant;
 use wp_parse_api::RawData;
 
 use super::types::Tags;
@@ -17,6 +19,10 @@ pub struct SourceEvent {
     pub ups_ip: Option<IpAddr>,
     /// 可选：parse 线程在进入 WPL 前调用
     pub preproc: Option<EventPreHook>,
+    /// 事件权重，用于按权重背压；默认 1
+    pub weight: u32,
+    /// 可选：事件进入系统（接收）的时间点，用于观测端到端延迟
+    pub ingress_at: Option<Instant>,
 }
 
 /// 一批源事件，便于批量传输；允许返回空 Vec 代表暂时无数据。
@@ -38,8 +44,223 @@ impl SourceEvent {
             tags,
             ups_ip: None,
             preproc: None,
+            weight: 1,
+            ingress_at: None,
         }
     }
+
+    pub fn with_weight(mut self, w: u32) -> Self {
+        self.weight = w;
+        self
+    }
+
+    pub fn with_upstream_ip(mut self, ip: IpAddr) -> Self {
+        self.ups_ip = Some(ip);
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Arc<Tags>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_preproc(mut self, hook: EventPreHook) -> Self {
+        self.preproc = Some(hook);
+        self
+    }
+
+    pub fn with_ingress_timestamp(mut self, ts: Instant) -> Self {
+        self.ingress_at = Some(ts);
+        self
+    }
+}
+
+/// 最小空帧：`event_id` 为 0、空 `src_key`、空字节负载、默认标签
+impl Default for SourceEvent {
+    fn default() -> Self {
+        Self::new(
+            0,
+            "",
+            RawData::Bytes(Default::default()),
+            Arc::new(Tags::default()),
+        )
+    }
+}
+
+/// 批次内事件权重之和
+pub fn total_weight(batch: &SourceBatch) -> u64 {
+    batch.iter().map(|e| u64::from(e.weight)).sum()
+}
+
+/// 按累计权重切分批次，返回 `(taken, rest)`。
+///
+/// 累计权重未达到 `max_weight` 前持续取事件，因此最后一个事件可能使 `taken` 略超出上限；
+/// `max_weight` 为 0 时不取任何事件。
+pub fn limit_batch_by_weight(
+    mut batch: SourceBatch,
+    max_weight: u64,
+) -> (SourceBatch, SourceBatch) {
+    let mut acc = 0u64;
+    let split = batch
+        .iter()
+        .position(|e| {
+            if acc >= max_weight {
+                return true;
+            }
+            acc += u64::from(e.weight);
+            false
+        })
+        .unwrap_or(batch.len());
+    let rest = batch.split_off(split);
+    (batch, rest)
+}
+
+/// 为批次内每个事件设置标签；共享的 `Tags` 经 `Arc::make_mut` 写时复制，值已相同时不复制。
+pub fn stamp_batch(batch: &mut SourceBatch, key: &str, value: &str) {
+    for event in batch.iter_mut() {
+        if event.tags.get(key) != Some(value) {
+            Arc::make_mut(&mut event.tags).set(key, value);
+        }
+    }
+}
+
+/// 让批次内所有事件共享同一组 `Tags`（替换原有标签）。
+pub fn stamp_batch_shared(batch: &mut SourceBatch, tags: Arc<Tags>) {
+    for event in batch.iter_mut() {
+        event.tags = tags.clone();
+    }
+}
+
+/// 仅为缺少 `key` 的事件设置标签，已有值保持不变。
+pub fn stamp_batch_if_absent(batch: &mut SourceBatch, key: &str, value: &str) {
+    for event in batch.iter_mut() {
+        if !event.tags.contains_key(key) {
+            Arc::make_mut(&mut event.tags).set(key, value);
+        }
+    }
+}
+
+/// 按标签谓词一次遍历拆分批次，返回 `(matching, not_matching)`，两侧保持原有顺序。
+pub fn filter_by_tags<F>(batch: SourceBatch, predicate: F) -> (SourceBatch, SourceBatch)
+where
+    F: Fn(&Tags) -> bool,
+{
+    batch.into_iter().partition(|e| predicate(&e.tags))
+}
+
+/// 按 `src_key` 分组，组内保持原有顺序。
+pub fn partition_by_src_key(batch: SourceBatch) -> HashMap<SmolStr, SourceBatch> {
+    let mut groups: HashMap<SmolStr, SourceBatch> = HashMap::new();
+    for event in batch {
+        groups.entry(event.src_key.clone()).or_default().push(event);
+    }
+    groups
+}
+
+/// 原地保留满足谓词的事件。
+pub fn retain_events(batch: &mut SourceBatch, predicate: impl Fn(&SourceEvent) -> bool) {
+    batch.retain(|e| predicate(e));
+}
+
+/// 按顺序拼接多个批次
+pub fn merge_batches(batches: Vec<SourceBatch>) -> SourceBatch {
+    let total = batches.iter().map(Vec::len).sum();
+    let mut merged = Vec::with_capacity(total);
+    for batch in batches {
+        merged.extend(batch);
+    }
+    merged
+}
+
+/// 仅保留满足谓词的事件，保持原有顺序。
+pub fn filter_batch(batch: SourceBatch, predicate: impl Fn(&SourceEvent) -> bool) -> SourceBatch {
+    batch.into_iter().filter(|e| predicate(e)).collect()
+}
+
+/// 对每个事件的负载执行 `f`，其余字段保持不变。
+pub fn map_payload(mut batch: SourceBatch, f: impl Fn(RawData) -> RawData) -> SourceBatch {
+    for event in batch.iter_mut() {
+        let payload = std::mem::replace(&mut event.payload, RawData::Bytes(Default::default()));
+        event.payload = f(payload);
+    }
+    batch
+}
+
+/// 按标签 `key` 的值分组，缺少该标签的事件归入 `""` 组；组内保持原有顺序。
+pub fn split_by_tag(batch: SourceBatch, key: &str) -> HashMap<String, SourceBatch> {
+    let mut groups: HashMap<String, SourceBatch> = HashMap::new();
+    for event in batch {
+        let bucket = event.tags.get(key).unwrap_or_default().to_string();
+        groups.entry(bucket).or_default().push(event);
+    }
+    groups
+}
+
+/// 将 `RawData::String` 负载转为 `RawData::ArcBytes`，字节内容不变（复用原有缓冲区）；
+/// 其他负载类型原样返回。
+pub fn promote_to_arc_bytes(mut event: SourceEvent) -> SourceEvent {
+    if let RawData::String(s) = &mut event.payload {
+        let bytes = std::mem::take(s).into_bytes();
+        event.payload = RawData::ArcBytes(Arc::new(bytes));
+    }
+    event
+}
+
+/// 对负载长度不小于 `threshold_bytes` 的事件执行 [`promote_to_arc_bytes`]，
+/// 使后续 fanout 仅复制 `Arc` 而非整段字符串。
+pub fn compact_batch(batch: SourceBatch, threshold_bytes: usize) -> SourceBatch {
+    batch
+        .into_iter()
+        .map(|event| {
+            if event.payload.len() >= threshold_bytes {
+                promote_to_arc_bytes(event)
+            } else {
+                event
+            }
+        })
+        .collect()
+}
+
+/// 批次统计信息，用于观测吞吐与来源分布。
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    pub event_count: usize,
+    pub total_payload_bytes: usize,
+    /// 最小事件 ID；空批次时为 0
+    pub min_event_id: u64,
+    /// 最大事件 ID；空批次时为 0
+    pub max_event_id: u64,
+    pub unique_src_keys: usize,
+}
+
+/// 统计单个批次的事件数、负载字节数、事件 ID 范围与不同 `src_key` 数量。
+pub fn batch_stats(batch: &SourceBatch) -> BatchStats {
+    collect_stats(batch.iter())
+}
+
+/// 汇总多个批次的统计信息；`unique_src_keys` 按所有批次去重计算。
+pub fn batches_stats(batches: &[SourceBatch]) -> BatchStats {
+    collect_stats(batches.iter().flatten())
+}
+
+fn collect_stats<'a>(events: impl Iterator<Item = &'a SourceEvent>) -> BatchStats {
+    let mut stats = BatchStats {
+        min_event_id: u64::MAX,
+        ..BatchStats::default()
+    };
+    let mut src_keys: HashSet<&str> = HashSet::new();
+    for event in events {
+        stats.event_count += 1;
+        stats.total_payload_bytes += event.payload.len();
+        stats.min_event_id = stats.min_event_id.min(event.event_id);
+        stats.max_event_id = stats.max_event_id.max(event.event_id);
+        src_keys.insert(event.src_key.as_str());
+    }
+    if stats.event_count == 0 {
+        stats.min_event_id = 0;
+    }
+    stats.unique_src_keys = src_keys.len();
+    stats
 }
 
 impl std::fmt::Debug for SourceEvent {
@@ -57,6 +278,11 @@ impl std::fmt::Debug for SourceEvent {
             )
             .field("tags", &format!("{} tags", self.tags.len()))
             .field("ups_ip", &self.ups_ip)
+            .field("weight", &self.weight)
+            .field(
+                "ingress_elapsed_ms",
+                &self.ingress_at.map(|ts| ts.elapsed().as_millis()),
+            )
             .finish()
     }
 }
@@ -78,6 +304,70 @@ mod tests {
         assert!(Arc::ptr_eq(&event.tags, &tags));
         assert!(event.ups_ip.is_none());
         assert!(event.preproc.is_none());
+        assert_eq!(event.weight, 1);
+        assert!(event.ingress_at.is_none());
+    }
+
+    #[test]
+    fn builder_methods_set_optional_fields() {
+        let tags = Arc::new(Tags::from(vec![("env".to_string(), "prod".to_string())]));
+        let hook: EventPreHook = Arc::new(|e: &mut SourceEvent| e.weight += 1);
+        let ip: IpAddr = "10.0.0.8".parse().unwrap();
+        let ts = Instant::now();
+
+        let mut event = SourceEvent::new(3, "k", RawData::from_string("x"), Arc::new(Tags::new()))
+            .with_upstream_ip(ip)
+            .with_tags(tags.clone())
+            .with_preproc(hook)
+            .with_ingress_timestamp(ts)
+            .with_weight(4);
+
+        assert_eq!(event.ups_ip, Some(ip));
+        assert!(Arc::ptr_eq(&event.tags, &tags));
+        assert_eq!(event.ingress_at, Some(ts));
+        assert_eq!(event.weight, 4);
+        let hook = event.preproc.clone().expect("preproc set");
+        hook(&mut event);
+        assert_eq!(event.weight, 5);
+
+        let debug = format!("{event:?}");
+        assert!(debug.contains("ingress_elapsed_ms: Some("));
+    }
+
+    #[test]
+    fn default_event_is_empty() {
+        let event = SourceEvent::default();
+        assert_eq!(event.event_id, 0);
+        assert!(event.src_key.is_empty());
+        assert!(matches!(event.payload, RawData::Bytes(ref b) if b.is_empty()));
+        assert!(event.tags.is_empty());
+        assert!(event.ups_ip.is_none());
+        assert!(event.ingress_at.is_none());
+        assert!(format!("{event:?}").contains("ingress_elapsed_ms: None"));
+    }
+
+    #[test]
+    fn limit_batch_by_weight_splits_on_cumulative_weight() {
+        let batch: SourceBatch = (0..5)
+            .map(|i| make_event(i, "w", "x").with_weight(10))
+            .collect();
+        assert_eq!(total_weight(&batch), 50);
+
+        let (taken, rest) = limit_batch_by_weight(batch.clone(), 25);
+        assert_eq!(taken.len(), 3);
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[0].event_id, 3);
+
+        let (taken, rest) = limit_batch_by_weight(batch.clone(), 20);
+        assert_eq!((taken.len(), rest.len()), (2, 3));
+
+        let (taken, rest) = limit_batch_by_weight(batch.clone(), 1_000);
+        assert_eq!((taken.len(), rest.len()), (5, 0));
+
+        let (taken, rest) = limit_batch_by_weight(batch, 0);
+        assert_eq!((taken.len(), rest.len()), (0, 5));
+
+        assert_eq!(total_weight(&Vec::new()), 0);
     }
 
     #[test]
@@ -92,4 +382,184 @@ mod tests {
         assert!(debug.contains("SourceEvent"));
         assert!(debug.contains("len=5"));
     }
+
+    fn make_event(id: u64, src: &str, payload: &str) -> SourceEvent {
+        SourceEvent::new(
+            id,
+            src,
+            RawData::from_string(payload),
+            Arc::new(Tags::default()),
+        )
+    }
+
+    #[test]
+    fn partition_and_filter_batch() {
+        let mut batch: SourceBatch = (0..6)
+            .map(|i| make_event(i, if i % 3 == 0 { "kafka" } else { "file" }, "x"))
+            .collect();
+        Arc::make_mut(&mut batch[1].tags).set("env", "prod");
+        Arc::make_mut(&mut batch[4].tags).set("env", "prod");
+
+        let groups = partition_by_src_key(batch.clone());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["kafka"].len(), 2);
+        assert_eq!(groups["file"].len(), 4);
+        let ids: Vec<u64> = groups["file"].iter().map(|e| e.event_id).collect();
+        assert_eq!(ids, vec![1, 2, 4, 5]);
+
+        let (prod, rest) = filter_by_tags(batch.clone(), |t| t.get("env") == Some("prod"));
+        assert_eq!(
+            prod.iter().map(|e| e.event_id).collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+        assert_eq!(rest.len(), 4);
+
+        retain_events(&mut batch, |e| e.src_key == "kafka");
+        assert_eq!(
+            batch.iter().map(|e| e.event_id).collect::<Vec<_>>(),
+            vec![0, 3]
+        );
+    }
+
+    #[test]
+    fn batch_helpers_handle_empty_input() {
+        assert!(merge_batches(Vec::new()).is_empty());
+        assert!(merge_batches(vec![Vec::new(), Vec::new()]).is_empty());
+        assert!(filter_batch(Vec::new(), |_| true).is_empty());
+        assert!(map_payload(Vec::new(), |p| p).is_empty());
+        assert!(split_by_tag(Vec::new(), "env").is_empty());
+    }
+
+    #[test]
+    fn batch_helpers_merge_filter_map_and_split() {
+        let merged = merge_batches(vec![
+            (0..4).map(|i| make_event(i, "a", "x")).collect(),
+            Vec::new(),
+            (4..10).map(|i| make_event(i, "b", "y")).collect(),
+        ]);
+        let ids: Vec<u64> = merged.iter().map(|e| e.event_id).collect();
+        assert_eq!(ids, (0..10).collect::<Vec<_>>());
+
+        let mut batch = map_payload(merged, |p| RawData::from_string(format!("<{p}>")));
+        assert_eq!(batch[0].payload.to_string(), "<x>");
+        assert_eq!(batch[9].payload.to_string(), "<y>");
+        for event in batch.iter_mut().take(9) {
+            let env = if event.event_id % 2 == 0 {
+                "prod"
+            } else {
+                "dev"
+            };
+            Arc::make_mut(&mut event.tags).set("env", env);
+        }
+
+        let groups = split_by_tag(batch.clone(), "env");
+        assert_eq!(groups.len(), 3);
+        let ids = |k: &str| groups[k].iter().map(|e| e.event_id).collect::<Vec<_>>();
+        assert_eq!(ids("prod"), vec![0, 2, 4, 6, 8]);
+        assert_eq!(ids("dev"), vec![1, 3, 5, 7]);
+        assert_eq!(ids(""), vec![9]);
+
+        let kept = filter_batch(batch, |e| e.src_key == "b");
+        assert_eq!(kept.len(), 6);
+        assert_eq!(kept[0].event_id, 4);
+    }
+
+    #[test]
+    fn compact_batch_promotes_large_string_payloads() {
+        let batch: SourceBatch = ["ab", "abcdefgh", "abcdefghij", "a", "0123456789abc"]
+            .iter()
+            .enumerate()
+            .map(|(i, p)| make_event(i as u64, "c", p))
+            .collect();
+        let compacted = compact_batch(batch, 8);
+
+        let kinds: Vec<bool> = compacted
+            .iter()
+            .map(|e| matches!(e.payload, RawData::ArcBytes(_)))
+            .collect();
+        assert_eq!(kinds, vec![false, true, true, false, true]);
+        assert!(matches!(compacted[0].payload, RawData::String(_)));
+        assert_eq!(compacted[2].payload.to_string(), "abcdefghij");
+        assert_eq!(compacted[4].payload.len(), 13);
+
+        let bytes = make_event(9, "c", "").with_weight(3);
+        let bytes = SourceEvent {
+            payload: RawData::from_arc_bytes(Arc::new(b"raw".to_vec())),
+            ..bytes
+        };
+        let promoted = promote_to_arc_bytes(bytes);
+        assert_eq!(promoted.weight, 3);
+        assert_eq!(promoted.payload.len(), 3);
+    }
+
+    #[test]
+    fn stamp_batch_sets_tag_with_copy_on_write() {
+        let shared = Arc::new(Tags::from(vec![("env".to_string(), "dev".to_string())]));
+        let mut batch = vec![make_event(1, "a", "x"), make_event(2, "a", "y")];
+        stamp_batch_shared(&mut batch, shared.clone());
+
+        stamp_batch(&mut batch, "run", "r1");
+        assert!(batch.iter().all(|e| e.tags.get("run") == Some("r1")));
+        assert!(batch.iter().all(|e| e.tags.get("env") == Some("dev")));
+        // 原共享标签不受影响
+        assert!(!shared.contains_key("run"));
+    }
+
+    #[test]
+    fn stamp_batch_shared_shares_one_arc() {
+        let tags = Arc::new(Tags::from(vec![("k".to_string(), "v".to_string())]));
+        let mut batch = vec![make_event(1, "a", "x"), make_event(2, "b", "y")];
+        stamp_batch_shared(&mut batch, tags.clone());
+        assert!(batch.iter().all(|e| Arc::ptr_eq(&e.tags, &tags)));
+
+        // 值相同时不触发复制
+        stamp_batch(&mut batch, "k", "v");
+        assert!(batch.iter().all(|e| Arc::ptr_eq(&e.tags, &tags)));
+    }
+
+    #[test]
+    fn stamp_batch_if_absent_keeps_existing_values() {
+        let mut batch = vec![make_event(1, "a", "x"), make_event(2, "a", "y")];
+        Arc::make_mut(&mut batch[0].tags).set("seq", "7");
+        stamp_batch_if_absent(&mut batch, "seq", "0");
+        assert_eq!(batch[0].tags.get("seq"), Some("7"));
+        assert_eq!(batch[1].tags.get("seq"), Some("0"));
+    }
+
+    #[test]
+    fn batch_stats_counts_bytes_ids_and_sources() {
+        let batch = vec![
+            make_event(10, "a", "x"),
+            make_event(11, "b", "xy"),
+            make_event(7, "a", "xyz"),
+            make_event(12, "b", "xyzw"),
+            make_event(9, "a", "xyzwv"),
+        ];
+        let stats = batch_stats(&batch);
+        assert_eq!(stats.event_count, 5);
+        assert_eq!(stats.total_payload_bytes, 15);
+        assert_eq!(stats.min_event_id, 7);
+        assert_eq!(stats.max_event_id, 12);
+        assert_eq!(stats.unique_src_keys, 2);
+    }
+
+    #[test]
+    fn batch_stats_empty_batch_is_zeroed() {
+        assert_eq!(batch_stats(&Vec::new()), BatchStats::default());
+    }
+
+    #[test]
+    fn batches_stats_aggregates_across_batches() {
+        let batches = vec![
+            vec![make_event(1, "a", "12"), make_event(2, "b", "34")],
+            vec![],
+            vec![make_event(5, "a", "5678"), make_event(3, "c", "9")],
+        ];
+        let stats = batches_stats(&batches);
+        assert_eq!(stats.event_count, 4);
+        assert_eq!(stats.total_payload_bytes, 9);
+        assert_eq!(stats.min_event_id, 1);
+        assert_eq!(stats.max_event_id, 5);
+        assert_eq!(stats.unique_src_keys, 3);
+    }
 }
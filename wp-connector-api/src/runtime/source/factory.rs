@@ -1,9 +1,12 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
+use super::combinator::MergedSource;
 use super::types::{CtrlRx, DataSource, Tags};
-use crate::{SourceDefProvider, SourceResult, types::ParamMap};
+use crate::config::param::{parammap_from_env, parammap_merge};
+use crate::{ConnectorKindAdapter, SourceDefProvider, SourceReason, SourceResult, types::ParamMap};
 
 #[async_trait]
 pub trait ServiceAcceptor: Send {
@@ -11,14 +14,101 @@ pub trait ServiceAcceptor: Send {
     async fn accept_connection(&mut self, ctrl_rx: CtrlRx) -> SourceResult<()>;
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct SourceBuildCtx {
     pub work_root: PathBuf,
+    /// 并行副本序号（从 0 开始），默认 0
+    pub replica_idx: usize,
+    /// 副本总数（>=1），默认 1
+    pub replica_cnt: usize,
+    /// 每秒事件数上限提示，0 表示不限速
+    pub rate_limit_rps: usize,
+    /// 注入环境变量时从变量名中去除的前缀
+    pub env_prefix: Option<String>,
+    /// 从环境变量读取的参数（如密钥），优先级高于 spec 参数
+    pub env_overrides: ParamMap,
+}
+
+impl std::fmt::Debug for SourceBuildCtx {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // 仅输出 key，避免泄露密钥
+        f.debug_struct("SourceBuildCtx")
+            .field("work_root", &self.work_root)
+            .field("replica_idx", &self.replica_idx)
+            .field("replica_cnt", &self.replica_cnt)
+            .field("rate_limit_rps", &self.rate_limit_rps)
+            .field("env_prefix", &self.env_prefix)
+            .field(
+                "env_overrides",
+                &self.env_overrides.keys().collect::<Vec<_>>(),
+            )
+            .finish()
+    }
 }
 
 impl SourceBuildCtx {
     pub fn new(work_root: PathBuf) -> Self {
-        Self { work_root }
+        Self {
+            work_root,
+            replica_idx: 0,
+            replica_cnt: 1,
+            rate_limit_rps: 0,
+            env_prefix: None,
+            env_overrides: ParamMap::new(),
+        }
+    }
+
+    /// 设置副本序号与总数，`cnt` 至少为 1（与 `SinkBuildCtx` 一致）。
+    pub fn with_replica(mut self, idx: usize, cnt: usize) -> Self {
+        self.replica_idx = idx;
+        self.replica_cnt = cnt.max(1);
+        self
+    }
+
+    /// 设置每秒事件数上限提示，0 表示不限速。
+    pub fn with_rate_limit(mut self, rps: usize) -> Self {
+        self.rate_limit_rps = rps;
+        self
+    }
+
+    /// 设置 `with_env_secrets` 读取变量时去除的前缀。
+    pub fn with_env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// 读取列出的环境变量写入 `env_overrides`；key 为去掉前缀后的小写变量名，未设置的变量忽略。
+    pub fn with_env_secrets(mut self, env_keys: Vec<String>) -> Self {
+        let secrets = parammap_from_env(self.env_prefix.as_deref(), &env_keys);
+        self.env_overrides.extend(secrets);
+        self
+    }
+
+    /// 将 `env_overrides` 覆盖到 `params` 上（环境变量优先）。
+    pub fn merge_with_env(&self, params: &mut ParamMap) {
+        for (k, v) in &self.env_overrides {
+            params.insert(k.clone(), v.clone());
+        }
+    }
+
+    /// 为 `n` 个并行 worker 拆分上下文：`work_root` 依次追加 `worker_{i}`，其余配置原样复制。
+    pub fn split(self, n: usize) -> Vec<Self> {
+        (0..n)
+            .map(|i| Self {
+                work_root: self.work_root.join(format!("worker_{i}")),
+                ..self.clone()
+            })
+            .collect()
+    }
+
+    /// 从 `work_root` 末级目录名 `worker_{i}` 中解析 worker 序号；非 `split` 产生的上下文返回 `None`。
+    pub fn worker_id(&self) -> Option<usize> {
+        self.work_root
+            .file_name()?
+            .to_str()?
+            .strip_prefix("worker_")?
+            .parse()
+            .ok()
     }
 }
 
@@ -28,6 +118,9 @@ pub struct SourceMeta {
     pub name: String,
     pub kind: String,
     pub tags: Tags,
+    /// UI 展示名，缺省时使用 `name`
+    pub display_name: Option<String>,
+    pub description: Option<String>,
 }
 
 impl SourceMeta {
@@ -36,14 +129,33 @@ impl SourceMeta {
             name: name.into(),
             kind: kind.into(),
             tags: Tags::default(),
+            display_name: None,
+            description: None,
         }
     }
+
+    pub fn with_display_name(mut self, n: impl Into<String>) -> Self {
+        self.display_name = Some(n.into());
+        self
+    }
+
+    pub fn with_description(mut self, d: impl Into<String>) -> Self {
+        self.description = Some(d.into());
+        self
+    }
+
+    /// 展示名：优先 `display_name`，否则回退到 `name`
+    pub fn effective_display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
+    }
 }
 
 /// 单个可注册的数据源实例。
 pub struct SourceHandle {
     pub source: Box<dyn DataSource + 'static>,
     pub metadata: SourceMeta,
+    /// 负载分配权重，默认 1；多源场景下 orchestrator 按权重分配拉取配额
+    pub weight: usize,
 }
 
 impl std::fmt::Debug for SourceHandle {
@@ -51,13 +163,33 @@ impl std::fmt::Debug for SourceHandle {
         f.debug_struct("SourceHandle")
             .field("source", &"Box<dyn DataSource>")
             .field("metadata", &self.metadata)
+            .field("weight", &self.weight)
             .finish()
     }
 }
 
 impl SourceHandle {
     pub fn new(source: Box<dyn DataSource + 'static>, metadata: SourceMeta) -> Self {
-        Self { source, metadata }
+        Self {
+            source,
+            metadata,
+            weight: 1,
+        }
+    }
+
+    pub fn with_weight(mut self, w: usize) -> Self {
+        self.weight = w;
+        self
+    }
+
+    /// 与 `SinkHandle::with_name` 对齐；名称统一保存在 `metadata.name`。
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.metadata.name = name.into();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.metadata.name
     }
 }
 
@@ -126,6 +258,79 @@ impl SourceSvcIns {
         self.acceptor = Some(acceptor);
         self
     }
+
+    /// 全部源（权重见各 handle 的 `weight`）
+    pub fn weighted_sources(&self) -> &[SourceHandle] {
+        &self.sources
+    }
+
+    /// 所有源权重之和
+    pub fn total_weight(&self) -> usize {
+        self.sources.iter().map(|h| h.weight).sum()
+    }
+
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// 将全部 sources 合并为单个 `DataSource`：无源返回 `None`，单个源直接返回，多个源包装为 `MergedSource`。
+    ///
+    /// 元信息与 acceptor 会被丢弃。
+    pub fn into_merged_source(self) -> Option<Box<dyn DataSource>> {
+        let mut sources: Vec<Box<dyn DataSource>> =
+            self.sources.into_iter().map(|h| h.source).collect();
+        match sources.len() {
+            0 => None,
+            1 => sources.pop(),
+            _ => Some(Box::new(MergedSource::new(sources))),
+        }
+    }
+}
+
+/// `SourceSvcIns` 的构建器，在 `build()` 时校验源数量下限。
+#[derive(Default)]
+pub struct SourceSvcInsBuilder {
+    sources: Vec<SourceHandle>,
+    acceptor: Option<AcceptorHandle>,
+    min_sources: Option<usize>,
+}
+
+impl SourceSvcInsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn source(mut self, h: SourceHandle) -> Self {
+        self.sources.push(h);
+        self
+    }
+
+    pub fn acceptor(mut self, a: AcceptorHandle) -> Self {
+        self.acceptor = Some(a);
+        self
+    }
+
+    /// 要求 `build()` 时至少包含 `n` 个源
+    pub fn require_min_sources(mut self, n: usize) -> Self {
+        self.min_sources = Some(n);
+        self
+    }
+
+    /// 源数量低于 `require_min_sources` 设定的下限时返回错误。
+    pub fn build(self) -> Result<SourceSvcIns, String> {
+        if let Some(min) = self.min_sources
+            && self.sources.len() < min
+        {
+            return Err(format!(
+                "source service requires at least {min} sources, got {}",
+                self.sources.len()
+            ));
+        }
+        Ok(SourceSvcIns {
+            sources: self.sources,
+            acceptor: self.acceptor,
+        })
+    }
 }
 
 /// ResolvedSourceSpec：统一 Factory 构建使用的规格（包含 connector_id，参数一律扁平）。
@@ -141,13 +346,154 @@ pub struct ResolvedSourceSpec {
     pub tags: Vec<String>,
 }
 
+impl ResolvedSourceSpec {
+    /// 设置（或覆盖）单个参数
+    pub fn with_param(mut self, key: impl Into<String>, val: serde_json::Value) -> Self {
+        self.params.insert(key.into(), val);
+        self
+    }
+
+    /// 合并 `extra` 到 `params`，同名键以 `extra` 为准
+    pub fn with_params(mut self, extra: ParamMap) -> Self {
+        self.params.extend(extra);
+        self
+    }
+
+    /// 创建 [`SourceSpecBuilder`]
+    pub fn builder() -> SourceSpecBuilder {
+        SourceSpecBuilder::default()
+    }
+
+    /// 移除参数（不存在时忽略）
+    pub fn without_param(mut self, key: &str) -> Self {
+        self.params.remove(key);
+        self
+    }
+}
+
+/// `ResolvedSourceSpec` 的构建器；`params`、`tags` 可选（默认为空），
+/// `build()` 时要求 `name`、`kind`、`connector_id` 均非空。
+#[derive(Debug, Clone, Default)]
+pub struct SourceSpecBuilder {
+    name: String,
+    kind: String,
+    connector_id: String,
+    params: ParamMap,
+    tags: Vec<String>,
+}
+
+impl SourceSpecBuilder {
+    pub fn name(mut self, s: impl Into<String>) -> Self {
+        self.name = s.into();
+        self
+    }
+
+    pub fn kind(mut self, s: impl Into<String>) -> Self {
+        self.kind = s.into();
+        self
+    }
+
+    pub fn connector_id(mut self, s: impl Into<String>) -> Self {
+        self.connector_id = s.into();
+        self
+    }
+
+    pub fn params(mut self, params: ParamMap) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn build(self) -> Result<ResolvedSourceSpec, String> {
+        for (field, val) in [
+            ("name", &self.name),
+            ("kind", &self.kind),
+            ("connector_id", &self.connector_id),
+        ] {
+            if val.is_empty() {
+                return Err(format!("source spec requires a non-empty '{field}'"));
+            }
+        }
+        Ok(ResolvedSourceSpec {
+            name: self.name,
+            kind: self.kind,
+            connector_id: self.connector_id,
+            params: self.params,
+            tags: self.tags,
+        })
+    }
+}
+
 #[async_trait]
 pub trait SourceFactory: SourceDefProvider + Send + Sync + 'static {
     fn kind(&self) -> &'static str;
     /// 可选：轻量级参数校验（不产生 I/O），用于尽早暴露参数错误。
-    fn validate_spec(&self, _spec: &ResolvedSourceSpec) -> SourceResult<()> {
+    ///
+    /// 默认对 `source_def().default_params` 与 `spec.params` 合并后的参数执行
+    /// `ConnectorDef::validate_params`（此时尚无环境变量覆盖，仅由环境变量提供的密钥不应列为必填）；
+    /// 若 `kind_adapter()` 返回适配器，再执行 `validate_spec_with_adapter`。
+    fn validate_spec(&self, spec: &ResolvedSourceSpec) -> SourceResult<()> {
+        let def = self.source_def();
+        def.validate_params(&parammap_merge(&def.default_params, &spec.params))
+            .map_err(SourceReason::SupplierError)?;
+        match self.kind_adapter() {
+            Some(adapter) => self.validate_spec_with_adapter(spec, adapter),
+            None => Ok(()),
+        }
+    }
+    /// 可选：与该工厂关联的 `ConnectorKindAdapter`，默认 `None`。
+    fn kind_adapter(&self) -> Option<&dyn ConnectorKindAdapter> {
+        None
+    }
+    /// 按 `adapter.required_params()` 校验 `adapter.defaults()` 与 `spec.params` 合并后的参数，
+    /// 失败返回 `SourceReason::SupplierError`。
+    fn validate_spec_with_adapter(
+        &self,
+        spec: &ResolvedSourceSpec,
+        adapter: &dyn ConnectorKindAdapter,
+    ) -> SourceResult<()> {
+        adapter
+            .validate_params(&parammap_merge(&adapter.defaults(), &spec.params))
+            .map_err(|e| {
+                SourceReason::SupplierError(format!("adapter '{}': {e}", adapter.kind())).into()
+            })
+    }
+    /// 异步校验：先执行 `validate_spec`，再调用 `probe_connectivity`，上游可达才视为有效。
+    async fn validate_spec_async(&self, spec: &ResolvedSourceSpec) -> SourceResult<()> {
+        self.validate_spec(spec)?;
+        self.probe_connectivity(spec).await
+    }
+    /// 可选：探测上游可达性；调度层在启动时及之后周期性调用，默认 no-op。
+    async fn probe_connectivity(&self, _spec: &ResolvedSourceSpec) -> SourceResult<()> {
+        Ok(())
+    }
+    /// 可选：build 前的连通性检查（DNS/TCP/鉴权），失败时调度层不应继续 build。
+    async fn warm_up(&self, _spec: &ResolvedSourceSpec, _ctx: &SourceBuildCtx) -> SourceResult<()> {
         Ok(())
     }
+    /// 带超时的 `warm_up`，超时返回 `SourceReason::Timeout`。
+    async fn warm_up_with_timeout(
+        &self,
+        spec: &ResolvedSourceSpec,
+        ctx: &SourceBuildCtx,
+        timeout: Duration,
+    ) -> SourceResult<()> {
+        match tokio::time::timeout(timeout, self.warm_up(spec, ctx)).await {
+            Ok(res) => res,
+            Err(_) => Err(SourceReason::Timeout(timeout).into()),
+        }
+    }
+    /// 合并后的运行参数：`source_def().default_params` < `spec.params` < `ctx.env_overrides`，
+    /// 供 `build()` 直接使用。
+    fn effective_params(&self, spec: &ResolvedSourceSpec, ctx: &SourceBuildCtx) -> ParamMap {
+        let mut params = parammap_merge(&self.source_def().default_params, &spec.params);
+        ctx.merge_with_env(&mut params);
+        params
+    }
     async fn build(
         &self,
         spec: &ResolvedSourceSpec,
@@ -158,10 +504,14 @@ pub trait SourceFactory: SourceDefProvider + Send + Sync + 'static {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runtime::source::SourceBatch;
+    use crate::runtime::source::{SourceBatch, SourceEvent};
+    use crate::{ConnectorDef, ConnectorScope};
     use async_trait::async_trait;
     use serde_json::json;
     use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wp_parse_api::RawData;
 
     #[derive(Default)]
     struct DummySource {
@@ -208,6 +558,169 @@ mod tests {
         assert_eq!(meta.tags.len(), 0);
     }
 
+    #[test]
+    fn source_meta_display_name_falls_back_to_name() {
+        let meta = SourceMeta::new("orders", "http");
+        assert_eq!(meta.effective_display_name(), "orders");
+        assert_eq!(meta.description, None);
+
+        let meta = meta
+            .with_display_name("Order Events")
+            .with_description("HTTP push endpoint for orders");
+        assert_eq!(meta.effective_display_name(), "Order Events");
+        assert_eq!(
+            meta.description.as_deref(),
+            Some("HTTP push endpoint for orders")
+        );
+    }
+
+    #[test]
+    fn source_build_ctx_injects_env_secrets() {
+        unsafe {
+            std::env::set_var("WPSRC_KAFKA_PASSWORD", "pw");
+        }
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"))
+            .with_env_secrets(vec!["WPSRC_KAFKA_PASSWORD".into()]);
+        assert_eq!(
+            ctx.env_overrides.get("wpsrc_kafka_password"),
+            Some(&json!("pw"))
+        );
+
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"))
+            .with_env_prefix("WPSRC")
+            .with_env_secrets(vec!["WPSRC_KAFKA_PASSWORD".into()]);
+        let mut params = ParamMap::new();
+        params.insert("brokers".into(), json!("localhost:9092"));
+        ctx.merge_with_env(&mut params);
+        assert_eq!(params.get("kafka_password"), Some(&json!("pw")));
+        assert_eq!(params.get("brokers"), Some(&json!("localhost:9092")));
+        assert!(!format!("{ctx:?}").contains("\"pw\""));
+    }
+
+    #[test]
+    fn source_build_ctx_builders_chain() {
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"));
+        assert_eq!(
+            (ctx.replica_idx, ctx.replica_cnt, ctx.rate_limit_rps),
+            (0, 1, 0)
+        );
+
+        let ctx = ctx
+            .with_replica(2, 4)
+            .with_rate_limit(1000)
+            .with_env_prefix("WPSRC".to_string());
+        assert_eq!(ctx.replica_idx, 2);
+        assert_eq!(ctx.replica_cnt, 4);
+        assert_eq!(ctx.rate_limit_rps, 1000);
+        assert_eq!(ctx.env_prefix.as_deref(), Some("WPSRC"));
+        assert!(format!("{ctx:?}").contains("replica_cnt: 4"));
+
+        let clamped = SourceBuildCtx::new(PathBuf::from("/tmp/source")).with_replica(0, 0);
+        assert_eq!(clamped.replica_cnt, 1);
+    }
+
+    #[test]
+    fn source_build_ctx_split_into_workers() {
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source")).with_env_prefix("WPSRC");
+        assert_eq!(ctx.worker_id(), None);
+
+        let workers = ctx.split(3);
+        let roots: Vec<PathBuf> = workers.iter().map(|c| c.work_root.clone()).collect();
+        assert_eq!(
+            roots,
+            vec![
+                PathBuf::from("/tmp/source/worker_0"),
+                PathBuf::from("/tmp/source/worker_1"),
+                PathBuf::from("/tmp/source/worker_2"),
+            ]
+        );
+        let ids: Vec<Option<usize>> = workers.iter().map(SourceBuildCtx::worker_id).collect();
+        assert_eq!(ids, vec![Some(0), Some(1), Some(2)]);
+        assert!(
+            workers
+                .iter()
+                .all(|c| c.env_prefix.as_deref() == Some("WPSRC"))
+        );
+        assert!(
+            SourceBuildCtx::new(PathBuf::from("/tmp/source"))
+                .split(0)
+                .is_empty()
+        );
+    }
+
+    struct MemorySource {
+        name: &'static str,
+        events: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl DataSource for MemorySource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            if self.events.is_empty() {
+                return Err(crate::SourceReason::EOF.into());
+            }
+            let payload = self.events.remove(0);
+            Ok(vec![SourceEvent::new(
+                0,
+                self.name,
+                RawData::from_string(payload),
+                Arc::new(Tags::new()),
+            )])
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            self.name.to_string()
+        }
+    }
+
+    fn memory_handle(name: &'static str, events: Vec<&'static str>) -> SourceHandle {
+        SourceHandle::new(
+            Box::new(MemorySource { name, events }),
+            SourceMeta::new(name, "memory"),
+        )
+    }
+
+    #[test]
+    fn into_merged_source_empty_and_single() {
+        assert!(SourceSvcIns::new().into_merged_source().is_none());
+
+        let single = SourceSvcIns::new()
+            .with_sources(vec![make_source_handle("only")])
+            .into_merged_source()
+            .unwrap();
+        assert_eq!(single.identifier(), "only");
+    }
+
+    #[tokio::test]
+    async fn into_merged_source_polls_all_sources() {
+        let svc = SourceSvcIns::new().with_sources(vec![
+            memory_handle("m1", vec!["a1", "a2"]),
+            memory_handle("m2", vec!["b1"]),
+            memory_handle("m3", vec!["c1", "c2", "c3"]),
+        ]);
+        let mut merged = svc.into_merged_source().unwrap();
+        assert_eq!(merged.identifier(), "merged:[3]");
+
+        let mut payloads = Vec::new();
+        while let Ok(batch) = merged.receive().await {
+            payloads.extend(batch.into_iter().map(|e| e.payload.to_string()));
+        }
+        payloads.sort();
+        assert_eq!(payloads, vec!["a1", "a2", "b1", "c1", "c2", "c3"]);
+    }
+
+    #[test]
+    fn source_handle_with_name_updates_metadata() {
+        let handle = make_source_handle("raw").with_name("kafka-in");
+        assert_eq!(handle.name(), "kafka-in");
+        assert_eq!(handle.metadata.name, "kafka-in");
+        assert_eq!(handle.metadata.kind, "dummy");
+    }
+
     #[test]
     fn handle_constructors_store_inner_state() {
         let meta = SourceMeta::new("alpha", "kafka");
@@ -231,6 +744,72 @@ mod tests {
         assert!(svc.acceptor.is_some());
     }
 
+    #[test]
+    fn source_svc_ins_weights() {
+        assert_eq!(make_source_handle("a").weight, 1);
+
+        let svc = SourceSvcIns::new().with_sources(vec![
+            make_source_handle("a").with_weight(1),
+            make_source_handle("b").with_weight(2),
+            make_source_handle("c").with_weight(3),
+        ]);
+        assert_eq!(svc.source_count(), 3);
+        assert_eq!(svc.total_weight(), 6);
+        let weights: Vec<usize> = svc.weighted_sources().iter().map(|h| h.weight).collect();
+        assert_eq!(weights, vec![1, 2, 3]);
+        assert_eq!(SourceSvcIns::new().total_weight(), 0);
+    }
+
+    #[test]
+    fn source_svc_ins_builder_checks_min_sources() {
+        let err = SourceSvcInsBuilder::new()
+            .require_min_sources(2)
+            .source(make_source_handle("a"))
+            .build()
+            .unwrap_err();
+        assert!(err.contains("at least 2"));
+
+        let svc = SourceSvcInsBuilder::new()
+            .require_min_sources(2)
+            .source(make_source_handle("a"))
+            .source(make_source_handle("b"))
+            .acceptor(AcceptorHandle::new("svc", Box::new(DummyAcceptor)))
+            .build()
+            .unwrap();
+        assert_eq!(svc.sources.len(), 2);
+        assert!(svc.acceptor.is_some());
+
+        assert!(
+            SourceSvcInsBuilder::new()
+                .build()
+                .unwrap()
+                .sources
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn resolved_source_spec_param_builders() {
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "demo",
+            "kind": "kafka",
+            "connector_id": "conn-1",
+            "params": {"group": "g1", "topic": "logs"}
+        }))
+        .unwrap();
+        let mut extra = ParamMap::new();
+        extra.insert("group".into(), json!("g2"));
+
+        let spec = spec
+            .with_param("timeout_ms", json!(5000))
+            .with_params(extra)
+            .without_param("topic")
+            .without_param("missing");
+        assert_eq!(spec.params["timeout_ms"], json!(5000));
+        assert_eq!(spec.params["group"], json!("g2"));
+        assert!(!spec.params.contains_key("topic"));
+    }
+
     #[test]
     fn resolved_source_spec_defaults_optional_fields() {
         let spec: ResolvedSourceSpec = serde_json::from_value(json!({
@@ -243,4 +822,358 @@ mod tests {
         assert!(spec.params.is_empty());
         assert!(spec.tags.is_empty());
     }
+
+    #[derive(Default)]
+    struct WarmUpFactory {
+        fail: bool,
+        slow: bool,
+        required: &'static [&'static str],
+        adapter: Option<&'static dyn ConnectorKindAdapter>,
+        builds: AtomicUsize,
+    }
+
+    struct FileAdapter;
+
+    impl ConnectorKindAdapter for FileAdapter {
+        fn kind(&self) -> &'static str {
+            "file"
+        }
+        fn defaults(&self) -> ParamMap {
+            ParamMap::from([("format".to_string(), json!("raw"))])
+        }
+        fn required_params(&self) -> &[&'static str] {
+            &["path", "format"]
+        }
+    }
+
+    impl SourceDefProvider for WarmUpFactory {
+        fn source_def(&self) -> ConnectorDef {
+            ConnectorDef {
+                id: "warm".into(),
+                kind: "warm".into(),
+                scope: ConnectorScope::Source,
+                allow_override: Vec::new(),
+                default_params: ParamMap::new(),
+                origin: None,
+                schema: (!self.required.is_empty()).then(|| json!({ "required": self.required })),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SourceFactory for WarmUpFactory {
+        fn kind(&self) -> &'static str {
+            "warm"
+        }
+
+        fn kind_adapter(&self) -> Option<&dyn ConnectorKindAdapter> {
+            self.adapter
+        }
+
+        async fn warm_up(
+            &self,
+            _spec: &ResolvedSourceSpec,
+            _ctx: &SourceBuildCtx,
+        ) -> SourceResult<()> {
+            if self.slow {
+                std::future::pending::<()>().await;
+            }
+            if self.fail {
+                return Err(SourceReason::Disconnect("dns failure".into()).into());
+            }
+            Ok(())
+        }
+
+        async fn build(
+            &self,
+            _spec: &ResolvedSourceSpec,
+            _ctx: &SourceBuildCtx,
+        ) -> SourceResult<SourceSvcIns> {
+            self.builds.fetch_add(1, Ordering::SeqCst);
+            Ok(SourceSvcIns::new())
+        }
+    }
+
+    #[test]
+    fn source_validate_spec_checks_required_params() {
+        let factory = WarmUpFactory {
+            required: &["path", "format"],
+            ..Default::default()
+        };
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "demo",
+            "kind": "warm",
+            "connector_id": "warm",
+            "params": {"format": "json"}
+        }))
+        .unwrap();
+        let err = factory.validate_spec(&spec).unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SourceReason::SupplierError("connector 'warm': missing required params: path".into())
+        );
+        let spec = spec.with_param("path", json!("/var/log/app.log"));
+        assert!(factory.validate_spec(&spec).is_ok());
+    }
+
+    #[test]
+    fn source_validate_spec_delegates_to_kind_adapter() {
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "demo",
+            "kind": "warm",
+            "connector_id": "warm"
+        }))
+        .unwrap();
+        assert!(WarmUpFactory::default().validate_spec(&spec).is_ok());
+
+        let factory = WarmUpFactory {
+            adapter: Some(&FileAdapter),
+            ..Default::default()
+        };
+        let err = factory.validate_spec(&spec).unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SourceReason::SupplierError("adapter 'file': missing required params: path".into())
+        );
+        let spec = spec.with_param("path", json!("/var/log/app.log"));
+        assert!(factory.validate_spec(&spec).is_ok());
+        assert!(
+            factory
+                .validate_spec_with_adapter(&spec, &FileAdapter)
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn source_warm_up_failure_skips_build() {
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "demo",
+            "kind": "warm",
+            "connector_id": "warm"
+        }))
+        .unwrap();
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"));
+
+        for (fail, expected_builds) in [(false, 1), (true, 0)] {
+            let factory = WarmUpFactory {
+                fail,
+                ..Default::default()
+            };
+            if factory
+                .warm_up_with_timeout(&spec, &ctx, Duration::from_millis(50))
+                .await
+                .is_ok()
+            {
+                factory.build(&spec, &ctx).await.unwrap();
+            }
+            assert_eq!(factory.builds.load(Ordering::SeqCst), expected_builds);
+        }
+    }
+
+    #[tokio::test]
+    async fn source_warm_up_times_out() {
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "demo",
+            "kind": "warm",
+            "connector_id": "warm"
+        }))
+        .unwrap();
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"));
+        let slow = WarmUpFactory {
+            slow: true,
+            ..Default::default()
+        };
+        let err = slow
+            .warm_up_with_timeout(&spec, &ctx, Duration::from_millis(50))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SourceReason::Timeout(Duration::from_millis(50))
+        );
+        assert!(err.reason().is_retryable());
+    }
+
+    struct PgFactory;
+
+    impl SourceDefProvider for PgFactory {
+        fn source_def(&self) -> ConnectorDef {
+            let mut default_params = ParamMap::new();
+            default_params.insert("host".into(), json!("localhost"));
+            default_params.insert("port".into(), json!(5433));
+            ConnectorDef {
+                id: "pg".into(),
+                kind: "postgres".into(),
+                scope: ConnectorScope::Source,
+                allow_override: vec!["*".into()],
+                default_params,
+                origin: None,
+                schema: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SourceFactory for PgFactory {
+        fn kind(&self) -> &'static str {
+            "postgres"
+        }
+
+        async fn build(
+            &self,
+            spec: &ResolvedSourceSpec,
+            ctx: &SourceBuildCtx,
+        ) -> SourceResult<SourceSvcIns> {
+            let params = self.effective_params(spec, ctx);
+            let host = params["host"].as_str().unwrap_or_default().to_string();
+            Ok(SourceSvcIns::new().with_sources(vec![make_source_handle("pg").with_name(host)]))
+        }
+    }
+
+    #[tokio::test]
+    async fn effective_params_merges_defaults_spec_and_env() {
+        unsafe {
+            std::env::set_var("WPEFF_SRC_HOST", "db.prod");
+        }
+        let spec: ResolvedSourceSpec = serde_json::from_value(json!({
+            "name": "pg",
+            "kind": "postgres",
+            "connector_id": "pg",
+            "params": {"port": 5432}
+        }))
+        .unwrap();
+        let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/source"))
+            .with_env_prefix("WPEFF_SRC")
+            .with_env_secrets(vec!["WPEFF_SRC_HOST".into()]);
+
+        let params = PgFactory.effective_params(&spec, &ctx);
+        let mut expected = ParamMap::new();
+        expected.insert("host".into(), json!("db.prod"));
+        expected.insert("port".into(), json!(5432));
+        assert_eq!(params, expected);
+
+        let svc = PgFactory.build(&spec, &ctx).await.unwrap();
+        assert_eq!(svc.sources[0].name(), "db.prod");
+    }
+
+    struct TcpProbeFactory;
+
+    impl SourceDefProvider for TcpProbeFactory {
+        fn source_def(&self) -> ConnectorDef {
+            ConnectorDef {
+                id: "tcp".into(),
+                kind: "tcp".into(),
+                scope: ConnectorScope::Source,
+                allow_override: Vec::new(),
+                default_params: ParamMap::new(),
+                origin: None,
+                schema: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SourceFactory for TcpProbeFactory {
+        fn kind(&self) -> &'static str {
+            "tcp"
+        }
+
+        fn validate_spec(&self, spec: &ResolvedSourceSpec) -> SourceResult<()> {
+            match spec.params.get("host").and_then(|v| v.as_str()) {
+                Some(_) => Ok(()),
+                None => Err(SourceReason::SupplierError("missing host".into()).into()),
+            }
+        }
+
+        async fn probe_connectivity(&self, spec: &ResolvedSourceSpec) -> SourceResult<()> {
+            let host = spec
+                .params
+                .get("host")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let addr: std::net::SocketAddr = host
+                .parse()
+                .map_err(|_| SourceReason::SupplierError(format!("bad host: {host}")))?;
+            std::net::TcpStream::connect_timeout(&addr, Duration::from_millis(200))
+                .map(|_| ())
+                .map_err(|e| SourceReason::Disconnect(format!("{host}: {e}")).into())
+        }
+
+        async fn build(
+            &self,
+            _spec: &ResolvedSourceSpec,
+            _ctx: &SourceBuildCtx,
+        ) -> SourceResult<SourceSvcIns> {
+            Ok(SourceSvcIns::new())
+        }
+    }
+
+    fn tcp_spec(host: Option<String>) -> ResolvedSourceSpec {
+        let mut params = ParamMap::new();
+        if let Some(host) = host {
+            params.insert("host".into(), json!(host));
+        }
+        ResolvedSourceSpec {
+            name: "tcp".into(),
+            kind: "tcp".into(),
+            connector_id: "tcp".into(),
+            params,
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn source_probe_connectivity_reports_unreachable_host() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open = listener.local_addr().unwrap().to_string();
+        let factory = TcpProbeFactory;
+        assert!(
+            factory
+                .validate_spec_async(&tcp_spec(Some(open.clone())))
+                .await
+                .is_ok()
+        );
+
+        // 关闭监听后端口不可达
+        drop(listener);
+        let err = factory
+            .validate_spec_async(&tcp_spec(Some(open)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err.reason(), SourceReason::Disconnect(_)));
+
+        // 同步校验失败时不再探测
+        let err = factory
+            .validate_spec_async(&tcp_spec(None))
+            .await
+            .unwrap_err();
+        assert!(matches!(err.reason(), SourceReason::SupplierError(_)));
+    }
+
+    #[test]
+    fn source_spec_builder_populates_and_validates() {
+        let mut params = ParamMap::new();
+        params.insert("path".into(), json!("/tmp/in.log"));
+        let spec = ResolvedSourceSpec::builder()
+            .name("in")
+            .kind("file")
+            .connector_id("file-main")
+            .params(params.clone())
+            .tags(vec!["env:prod".into()])
+            .build()
+            .unwrap();
+        assert_eq!(spec.name, "in");
+        assert_eq!(spec.kind, "file");
+        assert_eq!(spec.connector_id, "file-main");
+        assert_eq!(spec.params, params);
+        assert_eq!(spec.tags, vec!["env:prod".to_string()]);
+
+        let err = ResolvedSourceSpec::builder()
+            .name("")
+            .kind("file")
+            .connector_id("file-main")
+            .build()
+            .unwrap_err();
+        assert!(err.contains("'name'"));
+    }
 }
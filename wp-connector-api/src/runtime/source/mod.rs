@@ -1,10 +1,22 @@
+pub mod combinator;
 pub mod event;
 pub mod factory;
+pub mod stream;
 pub mod types;
 
-pub use event::{EventPreHook, SourceBatch, SourceEvent};
+pub use combinator::MergedSource;
+pub use event::{
+    BatchStats, EventPreHook, SourceBatch, SourceEvent, batch_stats, batches_stats, compact_batch,
+    filter_batch, filter_by_tags, limit_batch_by_weight, map_payload, merge_batches,
+    partition_by_src_key, promote_to_arc_bytes, retain_events, split_by_tag, stamp_batch,
+    stamp_batch_if_absent, stamp_batch_shared, total_weight,
+};
 pub use factory::{
     AcceptorHandle, ResolvedSourceSpec, ServiceAcceptor, SourceBuildCtx, SourceFactory,
-    SourceHandle, SourceMeta, SourceSvcIns,
+    SourceHandle, SourceMeta, SourceSpecBuilder, SourceSvcIns, SourceSvcInsBuilder,
+};
+pub use stream::StreamingSource;
+pub use types::{
+    AckToken, ControlEvent, CtrlRx, DataSource, OffsetAckToken, OffsetSeekPosition, SeekPosition,
+    SourceCaps, Tags, TimestampSeekPosition,
 };
-pub use types::{AckToken, ControlEvent, CtrlRx, DataSource, SeekPosition, SourceCaps, Tags};
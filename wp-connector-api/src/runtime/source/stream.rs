@@ -0,0 +1,155 @@
+use futures_core::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use super::event::SourceBatch;
+use super::types::DataSource;
+use crate::{SourceReason, SourceResult};
+
+type ReceiveFuture<S> = Pin<Box<dyn Future<Output = (S, SourceResult<SourceBatch>)> + Send>>;
+
+/// 将 `DataSource` 适配为 `futures_core::Stream`，每次 `poll_next` 驱动一次 `receive()`。
+///
+/// `SourceReason::EOF` 结束流；其他错误以 `Some(Err(..))` 产出，流可继续拉取。
+pub struct StreamingSource<S: DataSource> {
+    source: Option<S>,
+    pending: Option<ReceiveFuture<S>>,
+    finished: bool,
+}
+
+// `S` 只按值在 future 中移入移出，从不被 pin 住
+impl<S: DataSource> Unpin for StreamingSource<S> {}
+
+impl<S: DataSource + 'static> StreamingSource<S> {
+    pub fn new(source: S) -> Self {
+        Self {
+            source: Some(source),
+            pending: None,
+            finished: false,
+        }
+    }
+
+    /// 取回内部 source；`receive()` 尚未完成时返回 `None`
+    pub fn into_inner(self) -> Option<S> {
+        self.source
+    }
+}
+
+impl<S: DataSource + 'static> Stream for StreamingSource<S> {
+    type Item = SourceResult<SourceBatch>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.finished {
+            return Poll::Ready(None);
+        }
+        let fut = match this.pending.as_mut() {
+            Some(fut) => fut,
+            None => {
+                let Some(mut src) = this.source.take() else {
+                    return Poll::Ready(None);
+                };
+                this.pending.insert(Box::pin(async move {
+                    let res = src.receive().await;
+                    (src, res)
+                }))
+            }
+        };
+        let (src, res) = ready!(fut.as_mut().poll(cx));
+        this.pending = None;
+        this.source = Some(src);
+        match res {
+            Err(e) if matches!(e.reason(), SourceReason::EOF) => {
+                this.finished = true;
+                Poll::Ready(None)
+            }
+            other => Poll::Ready(Some(other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::source::{SourceEvent, Tags};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use wp_parse_api::RawData;
+
+    struct MemorySource {
+        batches: Vec<SourceBatch>,
+        fail_first: bool,
+    }
+
+    impl MemorySource {
+        fn new(payloads: &[&str]) -> Self {
+            let batches = payloads
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    vec![SourceEvent::new(
+                        i as u64,
+                        "mem",
+                        RawData::from_string(*p),
+                        Arc::new(Tags::new()),
+                    )]
+                })
+                .rev()
+                .collect();
+            Self {
+                batches,
+                fail_first: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DataSource for MemorySource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            if std::mem::take(&mut self.fail_first) {
+                return Err(SourceReason::Disconnect("flaky".into()).into());
+            }
+            self.batches.pop().ok_or_else(|| SourceReason::EOF.into())
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            "mem".into()
+        }
+    }
+
+    async fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        let mut out = Vec::new();
+        while let Some(item) = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await
+        {
+            out.push(item);
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn streaming_source_yields_batches_until_eof() {
+        let stream = MemorySource::new(&["a", "b", "c"]).into_stream();
+        let items = collect(stream).await;
+        let payloads: Vec<String> = items
+            .into_iter()
+            .flat_map(|batch| batch.unwrap())
+            .map(|e| e.payload.to_string())
+            .collect();
+        assert_eq!(payloads, vec!["a", "b", "c"]);
+    }
+
+    #[tokio::test]
+    async fn streaming_source_surfaces_errors_and_continues() {
+        let mut src = MemorySource::new(&["x"]);
+        src.fail_first = true;
+        let items = collect(StreamingSource::new(src)).await;
+        assert_eq!(items.len(), 2);
+        assert!(items[0].is_err());
+        assert_eq!(items[1].as_ref().unwrap().len(), 1);
+    }
+}
@@ -3,9 +3,12 @@ use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use smol_str::SmolStr;
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use super::event::SourceBatch;
+use super::stream::StreamingSource;
+use crate::types::ParamMap;
 use crate::{SourceReason, SourceResult};
 
 /// Capability flags for data sources.
@@ -33,6 +36,49 @@ pub trait AckToken: Send + Sync + std::fmt::Debug {}
 /// (e.g., timestamp, offset, sequence number).
 pub trait SeekPosition: Send + Sync + std::fmt::Debug {}
 
+/// Ack token for partitioned, offset-based sources (Kafka-style logs).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetAckToken {
+    pub partition: u32,
+    pub offset: u64,
+    pub source_id: String,
+}
+
+impl OffsetAckToken {
+    pub fn new(source_id: impl Into<String>, partition: u32, offset: u64) -> Self {
+        Self {
+            partition,
+            offset,
+            source_id: source_id.into(),
+        }
+    }
+}
+
+impl AckToken for OffsetAckToken {}
+
+/// Seek to an offset within a partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OffsetSeekPosition {
+    pub partition: u32,
+    pub offset: u64,
+}
+
+impl OffsetSeekPosition {
+    pub fn new(partition: u32, offset: u64) -> Self {
+        Self { partition, offset }
+    }
+}
+
+impl SeekPosition for OffsetSeekPosition {}
+
+/// Seek to the first event at or after a wall-clock time (Unix epoch milliseconds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimestampSeekPosition {
+    pub millis_since_epoch: i64,
+}
+
+impl SeekPosition for TimestampSeekPosition {}
+
 /// Control events for managing data source lifecycle.
 ///
 /// These events are sent through [`CtrlRx`] to control source behavior
@@ -45,6 +91,73 @@ pub enum ControlEvent {
     Isolate(bool),
     /// Request to seek to a specific position.
     Seek(Arc<dyn SeekPosition>),
+    /// Hot reconfiguration with a new parameter map; delivered via
+    /// [`DataSource::handle_control`] without restarting the source.
+    ConfigReload(Arc<ParamMap>),
+    /// Graceful shutdown: emit all buffered events, then report
+    /// `SourceReason::EOF` instead of stopping abruptly.
+    Drain,
+    /// Suspend output until a matching [`ControlEvent::Resume`].
+    Pause,
+    /// Resume output after a [`ControlEvent::Pause`].
+    Resume,
+    /// Adjust throughput to at most this many events per second; `0` = unlimited.
+    /// Delivered via [`DataSource::handle_control`].
+    SetRateLimit(usize),
+}
+
+impl ControlEvent {
+    /// Whether this event is handled by the orchestrator as part of the
+    /// source lifecycle (`Stop`, `Isolate`, `Seek`, `Drain`, `Pause`, `Resume`).
+    /// Other events are forwarded to [`DataSource::handle_control`].
+    pub fn is_lifecycle_event(&self) -> bool {
+        match self {
+            ControlEvent::Stop
+            | ControlEvent::Isolate(_)
+            | ControlEvent::Seek(_)
+            | ControlEvent::Drain
+            | ControlEvent::Pause
+            | ControlEvent::Resume => true,
+            ControlEvent::ConfigReload(_) | ControlEvent::SetRateLimit(_) => false,
+        }
+    }
+
+    /// Whether this event changes the source's run state
+    /// (`Stop`, `Drain`, `Pause`, `Resume`). `Isolate` and `Seek` only affect
+    /// output or position, so they return `false`, as do reconfiguration events.
+    pub fn is_lifecycle(&self) -> bool {
+        matches!(
+            self,
+            ControlEvent::Stop | ControlEvent::Drain | ControlEvent::Pause | ControlEvent::Resume
+        )
+    }
+}
+
+/// Serialize-only, for logs and audit trails, in serde's externally tagged
+/// form (`"Stop"`, `{"SetRateLimit": 100}`). `Seek` carries an opaque
+/// position and is written as its `Debug` text.
+impl Serialize for ControlEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        const NAME: &str = "ControlEvent";
+        match self {
+            ControlEvent::Stop => serializer.serialize_unit_variant(NAME, 0, "Stop"),
+            ControlEvent::Isolate(on) => {
+                serializer.serialize_newtype_variant(NAME, 1, "Isolate", on)
+            }
+            ControlEvent::Seek(pos) => {
+                serializer.serialize_newtype_variant(NAME, 2, "Seek", &format!("{pos:?}"))
+            }
+            ControlEvent::ConfigReload(params) => {
+                serializer.serialize_newtype_variant(NAME, 3, "ConfigReload", params.as_ref())
+            }
+            ControlEvent::Drain => serializer.serialize_unit_variant(NAME, 4, "Drain"),
+            ControlEvent::Pause => serializer.serialize_unit_variant(NAME, 5, "Pause"),
+            ControlEvent::Resume => serializer.serialize_unit_variant(NAME, 6, "Resume"),
+            ControlEvent::SetRateLimit(limit) => {
+                serializer.serialize_newtype_variant(NAME, 7, "SetRateLimit", limit)
+            }
+        }
+    }
 }
 
 /// Control channel receiver type.
@@ -146,6 +259,40 @@ pub trait DataSource: Send + Sync {
     async fn seek(&mut self, _pos: Arc<dyn SeekPosition>) -> SourceResult<()> {
         Err(SourceReason::SupplierError("seek unsupported".into()).into())
     }
+
+    /// Flush buffered state (e.g. pending offsets) before a checkpoint.
+    ///
+    /// The source stays usable afterwards. No-op by default.
+    async fn flush(&mut self) -> SourceResult<()> {
+        Ok(())
+    }
+
+    /// Liveness probe. Defaults to `Ok(())`.
+    ///
+    /// Must not block: implementations should answer from cached connection
+    /// state and complete in well under 100 ms.
+    async fn health_check(&self) -> SourceResult<()> {
+        Ok(())
+    }
+
+    /// Handle a non-lifecycle control event (e.g. `ConfigReload`).
+    ///
+    /// Called by the orchestrator for events where
+    /// [`ControlEvent::is_lifecycle_event`] is `false`. No-op by default;
+    /// sources supporting hot reload override this to apply the new params.
+    async fn handle_control(&mut self, _event: ControlEvent) -> SourceResult<()> {
+        Ok(())
+    }
+
+    /// Adapt this source into a `futures_core::Stream` of batches.
+    ///
+    /// The stream ends on `SourceReason::EOF`; other errors are yielded as items.
+    fn into_stream(self) -> StreamingSource<Self>
+    where
+        Self: Sized + 'static,
+    {
+        StreamingSource::new(self)
+    }
 }
 
 const INLINE_TAG_CAPACITY: usize = 16;
@@ -159,6 +306,11 @@ const INLINE_TAG_CAPACITY: usize = 16;
 /// Both keys and values use `SmolStr` for small string optimization,
 /// providing zero-allocation storage for strings ≤22 bytes.
 ///
+/// # Ordering guarantee
+/// Entries are always sorted by key and keys are unique. `iter()`, `keys()`
+/// and `values()` yield in ascending key order, so callers may rely on it
+/// (e.g. binary search over collected keys).
+///
 /// # Example
 /// ```ignore
 /// let mut tags = Tags::new();
@@ -181,6 +333,13 @@ impl Tags {
         }
     }
 
+    /// Build tags from `(key, value)` pairs in any order; later duplicates win.
+    pub fn from_pairs<K: Into<SmolStr>, V: Into<SmolStr>>(
+        pairs: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        pairs.into_iter().collect()
+    }
+
     /// Set a tag value. If the key exists, the value is updated.
     ///
     /// Keys are kept sorted for efficient lookup.
@@ -218,6 +377,11 @@ impl Tags {
             .is_ok()
     }
 
+    /// Check if any tag has the given value (linear scan).
+    pub fn contains_value(&self, value: &str) -> bool {
+        self.item.iter().any(|(_, v)| v.as_str() == value)
+    }
+
     /// Remove a tag by key.
     ///
     /// Returns the removed value if the key existed.
@@ -231,6 +395,48 @@ impl Tags {
         }
     }
 
+    /// Keep only the tags for which `f(key, value)` returns `true`.
+    ///
+    /// Removal preserves the relative order, so the sorted invariant holds.
+    pub fn retain<F: FnMut(&str, &str) -> bool>(&mut self, mut f: F) {
+        self.item.retain(|(k, v)| f(k.as_str(), v.as_str()));
+    }
+
+    /// Insert every entry of `other`, overwriting values for keys present in both.
+    pub fn merge(&mut self, other: &Tags) {
+        if other.is_empty() {
+            return;
+        }
+        // Both sides are sorted, so merge them in one pass instead of `set` per entry.
+        let mut merged = SmallVec::with_capacity(self.item.len() + other.item.len());
+        let mut mine = std::mem::take(&mut self.item).into_iter().peekable();
+        let mut theirs = other.item.iter().peekable();
+        loop {
+            let ord = match (mine.peek(), theirs.peek()) {
+                (Some((a, _)), Some((b, _))) => a.cmp(b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => break,
+            };
+            match ord {
+                std::cmp::Ordering::Less => merged.extend(mine.next()),
+                std::cmp::Ordering::Equal => {
+                    mine.next();
+                    merged.extend(theirs.next().cloned());
+                }
+                std::cmp::Ordering::Greater => merged.extend(theirs.next().cloned()),
+            }
+        }
+        self.item = merged;
+    }
+
+    /// Copy all entries out as owned pairs, in key order.
+    pub fn to_vec(&self) -> Vec<(String, String)> {
+        self.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
     /// Check if the collection is empty.
     pub fn is_empty(&self) -> bool {
         self.item.is_empty()
@@ -260,6 +466,102 @@ impl Tags {
     pub fn clear(&mut self) {
         self.item.clear();
     }
+
+    /// Parse a tag value as `i64`; `None` if missing or not an integer.
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Parse a tag value as `f64`; `None` if missing or not a number.
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// Parse a tag value as `bool`, accepting `true`/`false`, `1`/`0` and `yes`/`no`.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.get(key)? {
+            "true" | "1" | "yes" => Some(true),
+            "false" | "0" | "no" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Store an `i64` as its decimal string.
+    pub fn set_i64(&mut self, key: &str, val: i64) {
+        self.set(key, val.to_string());
+    }
+
+    /// Store an `f64` as its display string.
+    pub fn set_f64(&mut self, key: &str, val: f64) {
+        self.set(key, val.to_string());
+    }
+
+    /// Store a `bool` as `"true"` / `"false"`.
+    pub fn set_bool(&mut self, key: &str, val: bool) {
+        self.set(key, if val { "true" } else { "false" });
+    }
+
+    /// `true` when every key in `self` exists in `other` with the same value.
+    pub fn subset_of(&self, other: &Tags) -> bool {
+        self.merge_subset(other, true)
+    }
+
+    /// `true` when `other` is a subset of `self`.
+    pub fn superset_of(&self, other: &Tags) -> bool {
+        other.subset_of(self)
+    }
+
+    /// Like [`Self::subset_of`] but only compares keys, ignoring values.
+    pub fn keys_subset_of(&self, other: &Tags) -> bool {
+        self.merge_subset(other, false)
+    }
+
+    // Both sides are sorted by key, so a single merge pass is enough: O(n + m).
+    fn merge_subset(&self, other: &Tags, match_values: bool) -> bool {
+        let mut rest = other.item.iter();
+        'outer: for (key, value) in &self.item {
+            for (other_key, other_value) in rest.by_ref() {
+                match other_key.cmp(key) {
+                    std::cmp::Ordering::Less => continue,
+                    std::cmp::Ordering::Equal if !match_values || other_value == value => {
+                        continue 'outer;
+                    }
+                    _ => return false,
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+impl<K: Into<SmolStr>, V: Into<SmolStr>> Extend<(K, V)> for Tags {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.set(k, v);
+        }
+    }
+}
+
+/// Build tags from `(key, value)` pairs; later duplicates overwrite earlier ones.
+impl<K: Into<SmolStr>, V: Into<SmolStr>> FromIterator<(K, V)> for Tags {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tags = Tags::new();
+        tags.extend(iter);
+        tags
+    }
+}
+
+impl From<Vec<(String, String)>> for Tags {
+    fn from(pairs: Vec<(String, String)>) -> Self {
+        pairs.into_iter().collect()
+    }
+}
+
+impl From<BTreeMap<String, String>> for Tags {
+    fn from(map: BTreeMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
 }
 
 // Deprecated compatibility alias
@@ -278,6 +580,27 @@ mod tests {
 
     // ========== Tags tests ==========
 
+    #[test]
+    fn tags_typed_accessors() {
+        let mut tags = Tags::new();
+        tags.set("count", "42");
+        assert_eq!(tags.get_i64("count"), Some(42));
+        assert_eq!(tags.get_f64("count"), Some(42.0));
+        assert_eq!(tags.get_bool("count"), None);
+        assert_eq!(tags.get_i64("missing"), None);
+
+        tags.set_bool("encrypted", true);
+        tags.set("legacy", "no");
+        tags.set_f64("ratio", 0.25);
+        tags.set_i64("retry", -3);
+        assert_eq!(tags.get("encrypted"), Some("true"));
+        assert_eq!(tags.get_bool("encrypted"), Some(true));
+        assert_eq!(tags.get_bool("legacy"), Some(false));
+        assert_eq!(tags.get_f64("ratio"), Some(0.25));
+        assert_eq!(tags.get_i64("retry"), Some(-3));
+        assert_eq!(tags.get_i64("ratio"), None);
+    }
+
     #[test]
     fn tags_keep_sorted_insert_and_update() {
         let mut tags = Tags::new();
@@ -335,6 +658,66 @@ mod tests {
         assert_eq!(tags.len(), 2);
     }
 
+    #[test]
+    fn tags_retain_keeps_selected_in_order() {
+        let mut tags: Tags = vec![("d", "4"), ("a", "1"), ("c", "3"), ("b", "2")]
+            .into_iter()
+            .collect();
+        tags.retain(|k, v| k != "b" && v != "4");
+        assert_eq!(
+            tags.iter().collect::<Vec<_>>(),
+            vec![("a", "1"), ("c", "3")]
+        );
+        assert!(tags.remove("b").is_none());
+
+        // sorted invariant still holds for later inserts
+        tags.set("b", "2");
+        assert_eq!(tags.keys().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(tags.get("c"), Some("3"));
+    }
+
+    #[test]
+    fn tags_merge_overwrites_duplicates() {
+        let mut tags = Tags::from_pairs([("env", "dev"), ("app", "web"), ("zone", "a")]);
+        let other = Tags::from_pairs([("env", "prod"), ("build", "42"), ("zzz", "last")]);
+        tags.merge(&other);
+        assert_eq!(
+            tags.to_vec(),
+            vec![
+                ("app".to_string(), "web".to_string()),
+                ("build".to_string(), "42".to_string()),
+                ("env".to_string(), "prod".to_string()),
+                ("zone".to_string(), "a".to_string()),
+                ("zzz".to_string(), "last".to_string()),
+            ]
+        );
+        assert_eq!(other.len(), 3);
+
+        let mut empty = Tags::new();
+        empty.merge(&other);
+        assert_eq!(
+            empty.iter().collect::<Vec<_>>(),
+            other.iter().collect::<Vec<_>>()
+        );
+        empty.merge(&Tags::new());
+        assert_eq!(empty.len(), 3);
+    }
+
+    #[test]
+    fn tags_from_pairs_sorts_input() {
+        let tags = Tags::from_pairs(vec![
+            ("c".to_string(), "3".to_string()),
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "2".to_string()),
+            ("a".to_string(), "9".to_string()),
+        ]);
+        assert_eq!(
+            tags.iter().collect::<Vec<_>>(),
+            vec![("a", "9"), ("b", "2"), ("c", "3")]
+        );
+        assert_eq!(tags.get("b"), Some("2"));
+    }
+
     #[test]
     fn tags_iter() {
         let mut tags = Tags::new();
@@ -360,6 +743,21 @@ mod tests {
         assert_eq!(values, vec!["1", "2"]);
     }
 
+    #[test]
+    fn tags_keys_values_follow_key_order() {
+        let tags: Tags = vec![("c", "3"), ("a", "1"), ("b", "2")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(tags.keys().count(), 3);
+        assert_eq!(tags.values().count(), 3);
+        assert_eq!(tags.keys().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+        assert_eq!(tags.values().collect::<Vec<_>>(), vec!["1", "2", "3"]);
+
+        assert!(tags.contains_value("2"));
+        assert!(!tags.contains_value("b"));
+    }
+
     #[test]
     fn tags_clear() {
         let mut tags = Tags::new();
@@ -380,6 +778,55 @@ mod tests {
         assert_eq!(tags.get("key"), Some("value"));
     }
 
+    #[test]
+    fn tags_subset_and_superset() {
+        let small: Tags = [("env", "prod"), ("region", "us")].into_iter().collect();
+        let big: Tags = [("env", "prod"), ("region", "us"), ("tier", "premium")]
+            .into_iter()
+            .collect();
+        assert!(small.subset_of(&big));
+        assert!(big.superset_of(&small));
+        assert!(!big.subset_of(&small));
+        assert!(Tags::new().subset_of(&small));
+        assert!(small.subset_of(&small));
+
+        let staging: Tags = [("env", "staging")].into_iter().collect();
+        assert!(!staging.subset_of(&big));
+        assert!(staging.keys_subset_of(&big));
+
+        let other_key: Tags = [("zone", "a")].into_iter().collect();
+        assert!(!other_key.keys_subset_of(&big));
+    }
+
+    #[test]
+    fn tags_collect_from_pairs() {
+        let tags = vec![("b", "2"), ("a", "1")].into_iter().collect::<Tags>();
+        assert_eq!(tags.get("a"), Some("1"));
+        assert_eq!(tags.keys().collect::<Vec<_>>(), vec!["a", "b"]);
+
+        let owned: Tags = vec![
+            ("k".to_string(), "v1".to_string()),
+            ("k".into(), "v2".into()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(owned.len(), 1);
+        assert_eq!(owned.get("k"), Some("v2"));
+    }
+
+    #[test]
+    fn tags_extend_and_from_conversions() {
+        let mut tags = Tags::from(vec![("z".to_string(), "26".to_string())]);
+        tags.extend(vec![("y".to_string(), "25".to_string())]);
+        assert_eq!(tags.keys().collect::<Vec<_>>(), vec!["y", "z"]);
+
+        let map: BTreeMap<String, String> = [("env".to_string(), "prod".to_string())]
+            .into_iter()
+            .collect();
+        let tags = Tags::from(map);
+        assert_eq!(tags.get("env"), Some("prod"));
+    }
+
     // ========== ControlEvent tests ==========
 
     #[test]
@@ -425,6 +872,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn offset_positions_debug_and_trait_objects() {
+        let token = OffsetAckToken::new("kafka-a", 3, 42);
+        assert_eq!(
+            format!("{token:?}"),
+            r#"OffsetAckToken { partition: 3, offset: 42, source_id: "kafka-a" }"#
+        );
+        let token: Arc<dyn AckToken> = Arc::new(token);
+        assert!(format!("{token:?}").contains("offset: 42"));
+
+        let seek = OffsetSeekPosition::new(1, 100);
+        assert_eq!(
+            format!("{seek:?}"),
+            "OffsetSeekPosition { partition: 1, offset: 100 }"
+        );
+        let by_time = TimestampSeekPosition {
+            millis_since_epoch: 1_700_000_000_000,
+        };
+        let positions: Vec<Arc<dyn SeekPosition>> = vec![Arc::new(seek), Arc::new(by_time)];
+        assert_eq!(
+            format!("{:?}", positions[1]),
+            "TimestampSeekPosition { millis_since_epoch: 1700000000000 }"
+        );
+        assert!(matches!(
+            ControlEvent::Seek(positions[0].clone()),
+            ControlEvent::Seek(_)
+        ));
+    }
+
     // ========== SourceCaps tests ==========
 
     #[test]
@@ -576,7 +1052,12 @@ mod tests {
                     ControlEvent::Isolate(state) => {
                         isolated.store(state, Ordering::SeqCst);
                     }
-                    ControlEvent::Seek(_) => {}
+                    ControlEvent::Seek(_)
+                    | ControlEvent::ConfigReload(_)
+                    | ControlEvent::Drain
+                    | ControlEvent::Pause
+                    | ControlEvent::Resume
+                    | ControlEvent::SetRateLimit(_) => {}
                 }
             }
             Ok(())
@@ -617,6 +1098,123 @@ mod tests {
         assert!(source.isolated.load(Ordering::SeqCst));
     }
 
+    struct ReloadableSource {
+        config: ParamMap,
+        starts: usize,
+    }
+
+    #[async_trait]
+    impl DataSource for ReloadableSource {
+        async fn receive(&mut self) -> SourceResult<SourceBatch> {
+            Ok(vec![])
+        }
+
+        fn try_receive(&mut self) -> Option<SourceBatch> {
+            None
+        }
+
+        fn identifier(&self) -> String {
+            "reloadable".into()
+        }
+
+        async fn start(&mut self, _ctrl_rx: CtrlRx) -> SourceResult<()> {
+            self.starts += 1;
+            Ok(())
+        }
+
+        async fn handle_control(&mut self, event: ControlEvent) -> SourceResult<()> {
+            if let ControlEvent::ConfigReload(params) = event {
+                self.config = (*params).clone();
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn control_event_lifecycle_classification() {
+        assert!(ControlEvent::Stop.is_lifecycle_event());
+        assert!(ControlEvent::Isolate(true).is_lifecycle_event());
+        assert!(ControlEvent::Seek(Arc::new(TestSeekPos(1))).is_lifecycle_event());
+        assert!(!ControlEvent::ConfigReload(Arc::new(ParamMap::new())).is_lifecycle_event());
+        assert!(ControlEvent::Drain.is_lifecycle_event());
+        assert!(ControlEvent::Pause.is_lifecycle_event());
+        assert!(ControlEvent::Resume.is_lifecycle_event());
+        assert!(!ControlEvent::SetRateLimit(10).is_lifecycle_event());
+    }
+
+    #[test]
+    fn control_event_is_lifecycle_covers_run_state_changes() {
+        for event in [
+            ControlEvent::Stop,
+            ControlEvent::Drain,
+            ControlEvent::Pause,
+            ControlEvent::Resume,
+        ] {
+            assert!(event.is_lifecycle(), "{event:?}");
+        }
+        for event in [
+            ControlEvent::Isolate(true),
+            ControlEvent::Seek(Arc::new(TestSeekPos(1))),
+            ControlEvent::ConfigReload(Arc::new(ParamMap::new())),
+            ControlEvent::SetRateLimit(0),
+        ] {
+            assert!(!event.is_lifecycle(), "{event:?}");
+        }
+    }
+
+    #[test]
+    fn control_event_serializes_externally_tagged() {
+        use serde_json::json;
+
+        let to_json = |e: ControlEvent| serde_json::to_value(e).unwrap();
+        assert_eq!(to_json(ControlEvent::Drain), json!("Drain"));
+        assert_eq!(to_json(ControlEvent::Pause), json!("Pause"));
+        assert_eq!(to_json(ControlEvent::Resume), json!("Resume"));
+        assert_eq!(
+            to_json(ControlEvent::SetRateLimit(500)),
+            json!({"SetRateLimit": 500})
+        );
+        assert_eq!(to_json(ControlEvent::Stop), json!("Stop"));
+        assert_eq!(
+            to_json(ControlEvent::Isolate(true)),
+            json!({"Isolate": true})
+        );
+        assert_eq!(
+            to_json(ControlEvent::Seek(Arc::new(TestSeekPos(7)))),
+            json!({"Seek": "TestSeekPos(7)"})
+        );
+
+        let mut params = ParamMap::new();
+        params.insert("batch".into(), json!(64));
+        assert_eq!(
+            to_json(ControlEvent::ConfigReload(Arc::new(params))),
+            json!({"ConfigReload": {"batch": 64}})
+        );
+    }
+
+    #[tokio::test]
+    async fn datasource_applies_config_reload_without_restart() {
+        let mut source = ReloadableSource {
+            config: ParamMap::new(),
+            starts: 0,
+        };
+        let (_tx, rx) = async_broadcast::broadcast(4);
+        source.start(rx).await.unwrap();
+
+        let mut params = ParamMap::new();
+        params.insert("batch".into(), serde_json::json!(64));
+        let event = ControlEvent::ConfigReload(Arc::new(params));
+        assert!(!event.is_lifecycle_event());
+        source.handle_control(event).await.unwrap();
+
+        assert_eq!(source.config.get("batch"), Some(&serde_json::json!(64)));
+        assert_eq!(source.starts, 1);
+
+        // 默认实现为 no-op
+        let mut plain = LifecycleTrackingSource::new();
+        assert!(plain.handle_control(ControlEvent::Stop).await.is_ok());
+    }
+
     // ========== AckToken and SeekPosition tests ==========
 
     #[derive(Debug)]
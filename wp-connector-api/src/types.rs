@@ -1,4 +1,114 @@
 use std::collections::BTreeMap;
 
+use crate::{SinkError, SinkReason};
+
 // Core param map type used across connector config/build APIs
 pub type ParamMap = BTreeMap<String, serde_json::Value>;
+
+/// Typed accessors for [`ParamMap`]; each getter returns `None` when the key is
+/// missing or the value has a different JSON type.
+pub trait ParamMapExt {
+    fn get_str(&self, key: &str) -> Option<&str>;
+    fn get_i64(&self, key: &str) -> Option<i64>;
+    fn get_bool(&self, key: &str) -> Option<bool>;
+    /// Integers are accepted and widened to `f64`.
+    fn get_f64(&self, key: &str) -> Option<f64>;
+    /// `None` unless the value is an array whose elements are all strings.
+    fn get_array_of_str(&self, key: &str) -> Option<Vec<&str>>;
+    /// Like [`ParamMapExt::get_str`], but a missing or non-string value is a
+    /// `SinkReason::Sink` error naming the key.
+    fn require_str(&self, key: &str) -> Result<&str, SinkError>;
+}
+
+impl ParamMapExt for ParamMap {
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key)?.as_str()
+    }
+
+    fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get(key)?.as_i64()
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)?.as_bool()
+    }
+
+    fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key)?.as_f64()
+    }
+
+    fn get_array_of_str(&self, key: &str) -> Option<Vec<&str>> {
+        self.get(key)?
+            .as_array()?
+            .iter()
+            .map(|v| v.as_str())
+            .collect()
+    }
+
+    fn require_str(&self, key: &str) -> Result<&str, SinkError> {
+        self.get_str(key).ok_or_else(|| {
+            SinkError::from(SinkReason::Sink(format!(
+                "missing required string param '{key}'"
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn params() -> ParamMap {
+        let mut p = ParamMap::new();
+        p.insert("host".into(), json!("localhost"));
+        p.insert("port".into(), json!(9092));
+        p.insert("tls".into(), json!(true));
+        p.insert("ratio".into(), json!(0.5));
+        p.insert("topics".into(), json!(["a", "b"]));
+        p.insert("mixed".into(), json!(["a", 1]));
+        p
+    }
+
+    #[test]
+    fn typed_getters_present_and_missing() {
+        let p = params();
+        assert_eq!(p.get_str("host"), Some("localhost"));
+        assert_eq!(p.get_i64("port"), Some(9092));
+        assert_eq!(p.get_bool("tls"), Some(true));
+        assert_eq!(p.get_f64("ratio"), Some(0.5));
+        assert_eq!(p.get_f64("port"), Some(9092.0));
+        assert_eq!(p.get_array_of_str("topics"), Some(vec!["a", "b"]));
+
+        assert_eq!(p.get_str("nope"), None);
+        assert_eq!(p.get_i64("nope"), None);
+        assert_eq!(p.get_bool("nope"), None);
+        assert_eq!(p.get_f64("nope"), None);
+        assert_eq!(p.get_array_of_str("nope"), None);
+    }
+
+    #[test]
+    fn typed_getters_reject_wrong_types() {
+        let p = params();
+        assert_eq!(p.get_str("port"), None);
+        assert_eq!(p.get_i64("ratio"), None);
+        assert_eq!(p.get_bool("host"), None);
+        assert_eq!(p.get_f64("host"), None);
+        assert_eq!(p.get_array_of_str("mixed"), None);
+        assert_eq!(p.get_array_of_str("host"), None);
+    }
+
+    #[test]
+    fn require_str_reports_missing_key() {
+        let p = params();
+        assert_eq!(p.require_str("host").unwrap(), "localhost");
+
+        let err = p.require_str("brokers").unwrap_err();
+        assert_eq!(
+            err.reason(),
+            &SinkReason::Sink("missing required string param 'brokers'".into())
+        );
+        assert!(err.reason().to_string().contains("'brokers'"));
+        assert!(p.require_str("port").is_err());
+    }
+}
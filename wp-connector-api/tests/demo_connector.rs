@@ -103,11 +103,15 @@ impl MemorySinkBuffer {
 
 struct MemorySink {
     buffer: MemorySinkBuffer,
+    prefix: String,
 }
 
 impl MemorySink {
-    fn new(buffer: MemorySinkBuffer) -> Self {
-        Self { buffer }
+    fn new(buffer: MemorySinkBuffer, prefix: impl Into<String>) -> Self {
+        Self {
+            buffer,
+            prefix: prefix.into(),
+        }
     }
 }
 
@@ -140,7 +144,7 @@ impl AsyncRecordSink for MemorySink {
 #[async_trait]
 impl AsyncRawDataSink for MemorySink {
     async fn sink_str(&mut self, data: &str) -> SinkResult<()> {
-        self.buffer.push(data.to_string());
+        self.buffer.push(format!("{}{data}", self.prefix));
         Ok(())
     }
 
@@ -151,7 +155,7 @@ impl AsyncRawDataSink for MemorySink {
 
     async fn sink_str_batch(&mut self, data: Vec<&str>) -> SinkResult<()> {
         for s in data {
-            self.buffer.push(s.to_string());
+            self.buffer.push(format!("{}{s}", self.prefix));
         }
         Ok(())
     }
@@ -195,6 +199,7 @@ impl SourceDefProvider for DemoConnectorFactory {
             allow_override: vec!["events".into()],
             default_params: Default::default(),
             origin: Some("test".into()),
+            schema: None,
         }
     }
 }
@@ -208,6 +213,7 @@ impl SinkDefProvider for DemoConnectorFactory {
             allow_override: vec![],
             default_params: Default::default(),
             origin: Some("test".into()),
+            schema: None,
         }
     }
 }
@@ -218,8 +224,17 @@ impl SourceFactory for DemoConnectorFactory {
         "memory"
     }
 
-    async fn build(&self, spec: &SourceSpec, _ctx: &SourceBuildCtx) -> SourceResult<SourceSvcIns> {
-        let source = MemorySource::new(&spec.name, self.source_events.clone());
+    async fn build(&self, spec: &SourceSpec, ctx: &SourceBuildCtx) -> SourceResult<SourceSvcIns> {
+        // An `events` string array in the merged params replaces the built-in events
+        let params = SourceFactory::effective_params(self, spec, ctx);
+        let events = match params.get("events").and_then(|v| v.as_array()) {
+            Some(items) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect(),
+            None => self.source_events.clone(),
+        };
+        let source = MemorySource::new(&spec.name, events);
         let handle = SourceHandle::new(
             Box::new(source),
             SourceMeta::new(&spec.name, SourceFactory::kind(self)),
@@ -234,8 +249,11 @@ impl SinkFactory for DemoConnectorFactory {
         "memory"
     }
 
-    async fn build(&self, _spec: &SinkSpec, _ctx: &SinkBuildCtx) -> SinkResult<SinkHandle> {
-        let sink = MemorySink::new(self.sink_buffer.clone());
+    async fn build(&self, spec: &SinkSpec, ctx: &SinkBuildCtx) -> SinkResult<SinkHandle> {
+        // An optional `prefix` string in the merged params is prepended to written strings
+        let params = SinkFactory::effective_params(self, spec, ctx);
+        let prefix = params.get("prefix").and_then(|v| v.as_str()).unwrap_or("");
+        let sink = MemorySink::new(self.sink_buffer.clone(), prefix);
         Ok(SinkHandle::new(Box::new(sink)))
     }
 }
@@ -318,3 +336,48 @@ async fn test_def_providers_return_correct_metadata() {
     assert_eq!(sink_def.kind, "memory");
     assert_eq!(sink_def.scope, ConnectorScope::Sink);
 }
+
+#[tokio::test]
+async fn test_flush_and_health_check_defaults() {
+    let mut source = MemorySource::new("health", vec!["a".into()]);
+    assert!(source.flush().await.is_ok());
+    assert!(source.health_check().await.is_ok());
+
+    let mut sink = MemorySink::new(MemorySinkBuffer::default(), "");
+    assert!(sink.flush().await.is_ok());
+    assert!(sink.health_check().await.is_ok());
+}
+
+#[tokio::test]
+async fn test_source_build_uses_effective_params() {
+    let factory = DemoConnectorFactory::new(vec!["default".into()]);
+    let mut params = wp_connector_api::ParamMap::new();
+    params.insert("events".into(), serde_json::json!(["from-spec"]));
+    let spec = SourceSpec {
+        name: "param-source".into(),
+        kind: "memory".into(),
+        connector_id: "demo".into(),
+        params,
+        tags: vec![],
+    };
+    let ctx = SourceBuildCtx::new(PathBuf::from("/tmp/test"));
+    let mut svc = SourceFactory::build(&factory, &spec, &ctx).await.unwrap();
+
+    let batch = svc.sources[0].source.receive().await.unwrap();
+    assert_eq!(batch[0].payload.to_string(), "from-spec");
+}
+
+#[tokio::test]
+async fn test_sink_build_uses_effective_params() {
+    let factory = DemoConnectorFactory::new(vec![]);
+    let spec = SinkSpec::default().with_param("prefix", serde_json::json!("out:"));
+    let ctx = SinkBuildCtx::new(PathBuf::from("/tmp/test"));
+    let mut handle = SinkFactory::build(&factory, &spec, &ctx).await.unwrap();
+
+    handle.sink.sink_str("hello").await.unwrap();
+    handle.sink.sink_str_batch(vec!["a", "b"]).await.unwrap();
+    assert_eq!(
+        factory.sink_buffer().snapshot(),
+        vec!["out:hello", "out:a", "out:b"]
+    );
+}
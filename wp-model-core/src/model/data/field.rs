@@ -3,6 +3,8 @@ use crate::model::format::LevelFormatAble;
 use crate::model::{FNameStr, FValueStr};
 
 use crate::model::Value;
+use crate::model::fmt_def::TextFmt;
+use crate::model::types::value::value_to_json;
 use crate::traits::AsValueRef;
 use serde::Deserialize;
 use serde::Serialize;
@@ -78,6 +80,56 @@ impl<T> Field<T> {
     pub fn set_name<S: Into<FNameStr>>(&mut self, name: S) {
         self.name = name.into()
     }
+
+    /// 同时修改字段名与类型，值保持不变
+    pub fn rename_and_retype<S: Into<FNameStr>>(mut self, new_name: S, new_type: DataType) -> Self {
+        self.name = new_name.into();
+        self.meta = new_type;
+        self
+    }
+
+    pub fn with_name<S: Into<FNameStr>>(mut self, name: S) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn with_meta(mut self, meta: DataType) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    /// `with_name` 的同义方法
+    pub fn into_named<S: Into<FNameStr>>(self, name: S) -> Self {
+        self.with_name(name)
+    }
+
+    pub fn into_arc(self) -> Field<Arc<T>> {
+        Field {
+            meta: self.meta,
+            name: self.name,
+            value: Arc::new(self.value),
+        }
+    }
+
+    pub fn into_rc(self) -> Field<Rc<T>> {
+        Field {
+            meta: self.meta,
+            name: self.name,
+            value: Rc::new(self.value),
+        }
+    }
+}
+
+impl<T> Field<Arc<T>> {
+    /// 仅当值没有其他 `Arc` 引用时取回拥有型字段，否则返回 `None`
+    pub fn try_into_owned(self) -> Option<Field<T>> {
+        let value = Arc::try_unwrap(self.value).ok()?;
+        Some(Field {
+            meta: self.meta,
+            name: self.name,
+            value,
+        })
+    }
 }
 
 impl Field<Value> {
@@ -105,6 +157,29 @@ where
     pub fn get_value_mut(&mut self) -> &mut Value {
         self.value.as_value_mutref()
     }
+
+    /// 值的 `Display` 文本（不含字段名与类型）
+    pub fn to_string_lossy(&self) -> String {
+        self.get_value().to_string()
+    }
+
+    /// 按 `TextFmt` 输出单个值：`Json` / `Ndjson` 为 JSON 片段（字符串带引号，数值/布尔不带），
+    /// `Kv` 为 `name=value`，其余格式同 [`Field::to_string_lossy`]。
+    pub fn to_string_with_fmt(&self, fmt: TextFmt) -> String {
+        match fmt {
+            TextFmt::Json | TextFmt::Ndjson => value_to_json(self.get_value()).to_string(),
+            TextFmt::Kv => format!("{}={}", self.name, self.get_value()),
+            _ => self.to_string_lossy(),
+        }
+    }
+
+    /// 值的原始字节：字符串类变体直接取内部文本，其余按 `Display` 输出
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self.get_value().as_str_value() {
+            Some(s) => s.as_bytes().to_vec(),
+            None => self.to_string_lossy().into_bytes(),
+        }
+    }
 }
 
 impl<T> LevelFormatAble for Field<T>
@@ -202,6 +277,29 @@ mod tests {
         assert_eq!(field.get_name(), "new_name");
     }
 
+    #[test]
+    fn test_field_rename_and_retype() {
+        let field: DataField = Field::from_chars("raw_ts", "1700000000");
+        let field = field.rename_and_retype("new_name", DataType::Digit);
+        assert_eq!(field.get_name(), "new_name");
+        assert_eq!(field.get_meta(), &DataType::Digit);
+        assert_eq!(field.get_value(), &Value::Chars("1700000000".into()));
+    }
+
+    #[test]
+    fn test_field_builder_methods() {
+        let field: Field<i64> = Field::new(DataType::Chars, "a", 7)
+            .with_name("b")
+            .with_meta(DataType::Digit);
+        assert_eq!(field.get_name(), "b");
+        assert_eq!(field.get_meta(), &DataType::Digit);
+        assert_eq!(field.value, 7);
+
+        let field = field.into_named(String::from("c"));
+        assert_eq!(field.get_name(), "c");
+        assert_eq!(field.value, 7);
+    }
+
     // ========== ValueRef trait tests ==========
 
     #[test]
@@ -218,6 +316,22 @@ mod tests {
         assert_eq!(field.get_value(), &Value::Digit(99));
     }
 
+    #[test]
+    fn test_field_to_string_with_fmt() {
+        let digit: DataField = Field::new(DataType::Digit, "n", Value::Digit(42));
+        assert_eq!(digit.to_string_lossy(), "42");
+        assert_eq!(digit.to_string_with_fmt(TextFmt::Json), "42");
+        assert_eq!(digit.to_string_with_fmt(TextFmt::Raw), "42");
+        assert_eq!(digit.to_string_with_fmt(TextFmt::Kv), "n=42");
+
+        let chars = DataField::from_chars("msg", "say \"hi\"");
+        assert_eq!(chars.to_string_with_fmt(TextFmt::Json), r#""say \"hi\"""#);
+        assert_eq!(chars.to_string_with_fmt(TextFmt::Ndjson), r#""say \"hi\"""#);
+        assert_eq!(chars.to_string_with_fmt(TextFmt::Raw), "say \"hi\"");
+        assert_eq!(chars.to_bytes(), b"say \"hi\"".to_vec());
+        assert_eq!(digit.to_bytes(), b"42".to_vec());
+    }
+
     #[test]
     fn test_field_get_value_mut() {
         let mut field: DataField = Field::new(DataType::Digit, "num", Value::Digit(10));
@@ -270,6 +384,31 @@ mod tests {
         assert_eq!(*arc_field.value, "hello");
     }
 
+    #[test]
+    fn test_field_into_arc_and_rc() {
+        let field: Field<Value> = Field::new(DataType::Digit, "num", Value::Digit(7));
+        let arc_field = field.clone().into_arc();
+        assert_eq!(arc_field.get_name(), "num");
+        assert_eq!(arc_field.meta, DataType::Digit);
+        assert_eq!(*arc_field.value, Value::Digit(7));
+
+        let rc_field = field.into_rc();
+        assert_eq!(rc_field.get_name(), "num");
+        assert_eq!(*rc_field.value, Value::Digit(7));
+    }
+
+    #[test]
+    fn test_field_try_into_owned() {
+        let arc_field = Field::<Value>::new(DataType::Chars, "msg", Value::from("hi")).into_arc();
+        let shared = arc_field.value.clone();
+        assert!(arc_field.clone().try_into_owned().is_none());
+        drop(shared);
+
+        let owned = arc_field.try_into_owned().unwrap();
+        assert_eq!(owned.get_name(), "msg");
+        assert_eq!(owned.value, Value::from("hi"));
+    }
+
     // ========== Display tests ==========
 
     #[test]
@@ -0,0 +1,226 @@
+use crate::model::types::value::ObjectValue;
+use crate::model::{DataField, DataRecord, Value};
+
+impl DataRecord {
+    /// 将 `Value::Obj` / `Value::Array` 字段递归展开为顶层字段：对象子键以 `parent.child`
+    /// 命名，数组元素以 `list.0`、`list.1` 命名；`prefix` 非空时所有字段名前再加 `prefix.`。
+    ///
+    /// 空对象与空数组保留为单个字段；`Value::Ignore` 字段（含嵌套内的）被丢弃。
+    pub fn flatten(&self, prefix: &str) -> DataRecord {
+        let mut out = DataRecord::default();
+        for field in &self.items {
+            let name = if prefix.is_empty() {
+                field.name.to_string()
+            } else {
+                format!("{prefix}.{}", field.name)
+            };
+            flatten_into(&mut out, name, field);
+        }
+        out
+    }
+
+    /// [`DataRecord::flatten`] 的逆操作：按 `separator` 拆分字段名，并归并为嵌套的
+    /// `Value::Obj` 字段（同一父路径下的字段合并到同一对象，对象按首次出现位置排列）。
+    ///
+    /// 字段名含空段，或路径上已有同名的非对象字段时，该字段原样保留；
+    /// 不含分隔符的普通字段总是原样追加（与归并产生的对象同名也不合并，`DataRecord` 允许重名）；
+    /// 数组展开产生的下标键还原为对象键，`Value::Ignore` 字段被丢弃。
+    pub fn unflatten(&self, separator: char) -> DataRecord {
+        let mut out = DataRecord::default();
+        for field in &self.items {
+            if matches!(field.value, Value::Ignore(_)) {
+                continue;
+            }
+            let segs: Vec<&str> = field.name.split(separator).collect();
+            let Some((leaf, parents)) = segs.split_last() else {
+                out.append(field.clone());
+                continue;
+            };
+            if parents.is_empty()
+                || segs.iter().any(|s| s.is_empty())
+                || !can_nest(&out, &segs)
+                || !nest_into(&mut out, parents, leaf, field)
+            {
+                out.append(field.clone());
+            }
+        }
+        out
+    }
+}
+
+/// 将 `field` 写入 `parents` 路径下的对象；路径上遇到非对象时返回 `false`
+fn nest_into(out: &mut DataRecord, parents: &[&str], leaf: &str, field: &DataField) -> bool {
+    let Some((root, rest)) = parents.split_first() else {
+        return false;
+    };
+    let idx = match out.items.iter().position(|f| f.name == *root) {
+        Some(idx) => idx,
+        None => {
+            out.append(DataField::from_obj(*root, ObjectValue::new()));
+            out.items.len() - 1
+        }
+    };
+    let Value::Obj(top) = &mut out.items[idx].value else {
+        return false;
+    };
+    let mut cur = top;
+    for seg in rest {
+        let slot = cur.get_or_insert_with(seg, || DataField::from_obj(*seg, ObjectValue::new()));
+        let Value::Obj(obj) = &mut slot.value else {
+            return false;
+        };
+        cur = obj;
+    }
+    cur.insert(leaf, field.clone().with_name(leaf));
+    true
+}
+
+fn flatten_into(out: &mut DataRecord, name: String, field: &DataField) {
+    match &field.value {
+        Value::Ignore(_) => {}
+        Value::Obj(obj) if !obj.is_empty() => {
+            for (key, child) in obj.iter() {
+                flatten_into(out, format!("{name}.{key}"), child);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (idx, child) in items.iter().enumerate() {
+                flatten_into(out, format!("{name}.{idx}"), child);
+            }
+        }
+        _ => out.append(field.clone().with_name(name)),
+    }
+}
+
+/// 父路径上每一层要么不存在，要么是对象（顶层取第一个同名字段）
+fn can_nest(out: &DataRecord, segs: &[&str]) -> bool {
+    let parents = &segs[..segs.len() - 1];
+    let mut cur = match out.items.iter().find(|f| f.name == parents[0]) {
+        None => return true,
+        Some(DataField {
+            value: Value::Obj(obj),
+            ..
+        }) => obj,
+        Some(_) => return false,
+    };
+    for seg in &parents[1..] {
+        match cur.get(seg).map(|f| &f.value) {
+            None => return true,
+            Some(Value::Obj(obj)) => cur = obj,
+            Some(_) => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DataType;
+
+    fn nested() -> DataRecord {
+        let mut geo = ObjectValue::new();
+        geo.insert("lat", DataField::from_float("lat", 30.5));
+        geo.insert("city", DataField::from_chars("city", "hz"));
+        let mut user = ObjectValue::new();
+        user.insert("id", DataField::from_digit("id", 7));
+        user.insert("geo", DataField::from_obj("geo", geo));
+
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("msg", "hello"));
+        record.append(DataField::from_obj("user", user));
+        record
+    }
+
+    // ========== flatten tests ==========
+
+    #[test]
+    fn test_flatten_objects_and_arrays() {
+        let mut record = nested();
+        record.append(DataField::from_arr(
+            "list",
+            vec![DataField::from_digit("", 1), DataField::from_digit("", 2)],
+        ));
+        record.append(DataField::new(
+            DataType::Ignore,
+            "skip",
+            Value::Ignore(Default::default()),
+        ));
+
+        let flat = record.flatten("");
+        let names: Vec<&str> = flat.items.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "msg",
+                "user.geo.city",
+                "user.geo.lat",
+                "user.id",
+                "list.0",
+                "list.1"
+            ]
+        );
+        assert_eq!(flat.get_value("user.geo.city"), Some(&Value::from("hz")));
+        assert_eq!(flat.get_value("list.1"), Some(&Value::Digit(2)));
+
+        let prefixed = nested().flatten("evt");
+        assert_eq!(prefixed.get_value("evt.user.id"), Some(&Value::Digit(7)));
+        assert_eq!(prefixed.get_value("evt.msg"), Some(&Value::from("hello")));
+    }
+
+    // ========== unflatten tests ==========
+
+    #[test]
+    fn test_flatten_unflatten_roundtrip() {
+        let record = nested();
+        let back = record.flatten("").unflatten('.');
+        assert_eq!(back, record);
+    }
+
+    #[test]
+    fn test_unflatten_keeps_conflicting_and_invalid_names() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("a", 1));
+        record.append(DataField::from_chars("a.b", "x"));
+        record.append(DataField::from_chars("c..d", "y"));
+        record.append(DataField::from_digit("e/f", 2));
+
+        let back = record.unflatten('.');
+        assert_eq!(back.get_value("a"), Some(&Value::Digit(1)));
+        assert_eq!(back.get_value("a.b"), Some(&Value::from("x")));
+        assert_eq!(back.get_value("c..d"), Some(&Value::from("y")));
+
+        let slash = record.unflatten('/');
+        let Some(Value::Obj(e)) = slash.get_value("e") else {
+            panic!("expected object field 'e'");
+        };
+        assert_eq!(e.get("f").map(|f| &f.value), Some(&Value::Digit(2)));
+    }
+
+    #[test]
+    fn test_unflatten_plain_field_after_nested_is_kept() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("a.b", 1));
+        record.append(DataField::from_chars("a", "plain"));
+        let mut extra = ObjectValue::new();
+        extra.insert("c", DataField::from_digit("c", 3));
+        extra.insert("b", DataField::from_digit("b", 9));
+        record.append(DataField::from_obj("a", extra.clone()));
+
+        let back = record.unflatten('.');
+        assert_eq!(back.items.len(), 3);
+        let Value::Obj(nested) = &back.items[0].value else {
+            panic!("expected object field 'a'");
+        };
+        assert_eq!(nested.len(), 1);
+        assert_eq!(nested.get("b").map(|f| &f.value), Some(&Value::Digit(1)));
+        assert_eq!(back.items[1], DataField::from_chars("a", "plain"));
+        assert_eq!(back.items[2], DataField::from_obj("a", extra));
+
+        // 普通字段在前时同样不丢字段
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("a", "plain"));
+        record.append(DataField::from_digit("a.b", 1));
+        assert_eq!(record.unflatten('.').items, record.items);
+    }
+}
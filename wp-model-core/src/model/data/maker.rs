@@ -1,10 +1,11 @@
 use crate::model::{
     DataType, DateTimeValue, DomainT, EmailT, FNameStr, FValueStr, HexT, IdCardT, IgnoreT, Maker,
-    MobilePhoneT, UrlValue, Value,
+    MobilePhoneT, SerialNumberT, UrlValue, Value,
     types::value::{ObjectValue, SymbolValue},
 };
 use smol_str::SmolStr;
 use std::net::IpAddr;
+use std::time::Duration;
 
 use super::Field;
 
@@ -128,6 +129,15 @@ where
     }
 }
 
+impl<T> Field<T>
+where
+    T: Maker<SerialNumberT>,
+{
+    pub fn from_sn<S: Into<FNameStr>>(name: S, sn: SerialNumberT) -> Self {
+        Self::new(DataType::SN, name.into(), T::make(sn))
+    }
+}
+
 impl<T> Field<T>
 where
     T: Maker<IgnoreT>,
@@ -146,6 +156,14 @@ where
     }
 }
 impl<T> Field<T>
+where
+    T: Maker<Duration>,
+{
+    pub fn from_duration<S: Into<FNameStr>>(name: S, val: Duration) -> Self {
+        Self::new(DataType::Duration, name.into(), T::make(val))
+    }
+}
+impl<T> Field<T>
 where
     T: Maker<Vec<Field<Value>>>,
 {
@@ -169,6 +187,16 @@ where
     }
 }
 
+#[cfg(feature = "uuid-support")]
+impl<T> Field<T>
+where
+    T: Maker<uuid::Uuid>,
+{
+    pub fn from_uuid<S: Into<FNameStr>>(name: S, val: uuid::Uuid) -> Self {
+        Self::new(DataType::Uuid, name.into(), T::make(val))
+    }
+}
+
 impl Value {
     pub fn tag(&self) -> &str {
         match self {
@@ -178,10 +206,13 @@ impl Value {
             Value::Symbol(_) => "Symbol",
             Value::Digit(_) => "Digit",
             Value::Time(_) => "Time",
+            Value::Duration(_) => "Duration",
             Value::Hex(_) => "Hex",
+            Value::Uuid(_) => "Uuid",
             Value::Float(_) => "Float",
             Value::IpNet(_) => "IpNet",
             Value::IpAddr(_) => "IpAddr",
+            Value::MacAddr(_) => "MacAddr",
             Value::Ignore(_) => "Ignore",
             Value::Obj(_) => "Map",
             Value::Array(_) => "Array",
@@ -190,23 +221,28 @@ impl Value {
             Value::Email(_) => "Email",
             Value::IdCard(_) => "IdCard",
             Value::MobilePhone(_) => "MobilePhone",
+            Value::SN(_) => "SN",
         }
     }
 
     pub fn is_empty(&self) -> bool {
         match self {
             Value::Time(_)
+            | Value::Duration(_)
             | Value::IpNet(_)
             | Value::IpAddr(_)
+            | Value::MacAddr(_)
             | Value::Float(_)
             | Value::Digit(_)
             | Value::Bool(_)
             | Value::Hex(_) => false,
+            Value::Uuid(_) => false,
             Value::Domain(v) => v.0.is_empty(),
             Value::Url(v) => v.0.is_empty(),
             Value::Email(v) => v.0.is_empty(),
             Value::IdCard(v) => v.0.is_empty(),
             Value::MobilePhone(v) => v.0.is_empty(),
+            Value::SN(v) => v.0.is_empty(),
             Value::Chars(v) => v.is_empty(),
             Value::Obj(v) => v.is_empty(),
             Value::Array(v) => v.is_empty(),
@@ -330,6 +366,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_field_from_sn() {
+        let sn = crate::model::types::value::parse_sn("SN-00123").unwrap();
+        let field: DataField = Field::from_sn("serial", sn.clone());
+        assert_eq!(field.get_name(), "serial");
+        assert_eq!(field.meta, DataType::SN);
+        assert_eq!(field.value, Value::SN(sn));
+        assert_eq!(field.value.tag(), "SN");
+        assert!(!field.value.is_empty());
+        assert!(Value::SN(SerialNumberT(String::new(), None)).is_empty());
+    }
+
     #[test]
     fn test_field_from_ignore() {
         let field: DataField = Field::from_ignore("unused");
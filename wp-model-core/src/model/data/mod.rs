@@ -1,7 +1,14 @@
 pub mod field;
+pub mod flatten;
 pub mod maker;
 pub mod map;
+pub mod patch;
 pub mod record;
+pub mod stats;
+pub mod versioned;
 pub use field::Field;
 pub use map::ObjectMap;
-pub use record::Record;
+pub use patch::{JsonPatchOp, apply_json_patch};
+pub use record::{FlatRecord, Record, RecordDiff, group_by_field, partition_by};
+pub use stats::{ColumnStats, column_stats};
+pub use versioned::VersionedRecord;
@@ -0,0 +1,184 @@
+use crate::model::DataRecord;
+use crate::model::error::ModelError;
+use crate::model::types::value::{json_field, value_to_json};
+use serde::{Deserialize, Serialize};
+
+/// RFC 6902 JSON Patch 操作；序列化形式为 `{"op": "add", "path": "/name", "value": ..}`。
+///
+/// `path` 为 JSON Pointer（RFC 6901），仅支持指向顶层字段的 `/<name>`（`~1` → `/`，`~0` → `~`）。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum JsonPatchOp {
+    Add {
+        path: String,
+        value: serde_json::Value,
+    },
+    Remove {
+        path: String,
+    },
+    Replace {
+        path: String,
+        value: serde_json::Value,
+    },
+    Test {
+        path: String,
+        value: serde_json::Value,
+    },
+}
+
+/// 按顺序对记录应用 JSON Patch，整体原子：任一操作失败时记录保持不变。
+///
+/// - `Add`：字段已存在时替换，否则追加到末尾；
+/// - `Remove` / `Replace`：目标字段必须存在；
+/// - `Test`：当前值（转换为 JSON）与给定值不等时返回 `ModelError::Validation`。
+///
+/// 新值类型由 [`crate::model::DataType::infer_from_value`] 推断；同名字段仅作用于第一个。
+pub fn apply_json_patch(record: &mut DataRecord, ops: &[JsonPatchOp]) -> Result<(), ModelError> {
+    let mut work = record.clone();
+    for op in ops {
+        apply_op(&mut work, op)?;
+    }
+    *record = work;
+    Ok(())
+}
+
+fn apply_op(record: &mut DataRecord, op: &JsonPatchOp) -> Result<(), ModelError> {
+    match op {
+        JsonPatchOp::Add { path, value } => {
+            let name = field_name(path)?;
+            let field = json_field(&name, value.clone())?;
+            match record.get_value_mut(&name) {
+                Some(slot) => *slot = field,
+                None => record.append(field),
+            }
+        }
+        JsonPatchOp::Remove { path } => {
+            let name = field_name(path)?;
+            if !record.remove_field(&name) {
+                return Err(missing(path));
+            }
+        }
+        JsonPatchOp::Replace { path, value } => {
+            let name = field_name(path)?;
+            let field = json_field(&name, value.clone())?;
+            *record.get_value_mut(&name).ok_or_else(|| missing(path))? = field;
+        }
+        JsonPatchOp::Test { path, value } => {
+            let name = field_name(path)?;
+            let current = record.field(&name).ok_or_else(|| missing(path))?;
+            let current = value_to_json(current.get_value());
+            if &current != value {
+                return Err(ModelError::Validation(format!(
+                    "json patch test failed at '{path}': expected {value}, got {current}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn field_name(path: &str) -> Result<String, ModelError> {
+    match path.strip_prefix('/') {
+        Some(name) if !name.is_empty() && !name.contains('/') => {
+            Ok(name.replace("~1", "/").replace("~0", "~"))
+        }
+        _ => Err(ModelError::Parse(format!(
+            "unsupported json patch path '{path}', expected '/<field>'"
+        ))),
+    }
+}
+
+fn missing(path: &str) -> ModelError {
+    ModelError::Validation(format!("json patch target '{path}' does not exist"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DataField, DataType, Value};
+    use serde_json::json;
+
+    fn sample() -> DataRecord {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("name", "alice"));
+        record.append(DataField::from_digit("age", 30));
+        record.append(DataField::from_bool("tmp", true));
+        record
+    }
+
+    // ========== apply_json_patch tests ==========
+
+    #[test]
+    fn test_apply_json_patch_add_replace_remove() {
+        let mut record = sample();
+        let ops = vec![
+            JsonPatchOp::Add {
+                path: "/city".into(),
+                value: json!("hangzhou"),
+            },
+            JsonPatchOp::Replace {
+                path: "/age".into(),
+                value: json!(31),
+            },
+            JsonPatchOp::Remove {
+                path: "/tmp".into(),
+            },
+        ];
+        apply_json_patch(&mut record, &ops).unwrap();
+
+        let names: Vec<&str> = record.items.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["name", "age", "city"]);
+        assert_eq!(record.get_value("age"), Some(&Value::Digit(31)));
+        assert_eq!(record.get_value("city"), Some(&Value::from("hangzhou")));
+        assert_eq!(record.field("city").unwrap().get_meta(), &DataType::Chars);
+    }
+
+    #[test]
+    fn test_apply_json_patch_test_op_and_atomicity() {
+        let mut record = sample();
+        let ok = [JsonPatchOp::Test {
+            path: "/age".into(),
+            value: json!(30),
+        }];
+        assert!(apply_json_patch(&mut record, &ok).is_ok());
+
+        let ops = [
+            JsonPatchOp::Remove {
+                path: "/name".into(),
+            },
+            JsonPatchOp::Test {
+                path: "/age".into(),
+                value: json!(99),
+            },
+        ];
+        let err = apply_json_patch(&mut record, &ops).unwrap_err();
+        assert!(matches!(err, ModelError::Validation(_)));
+        assert_eq!(record, sample());
+    }
+
+    #[test]
+    fn test_apply_json_patch_errors_and_serde() {
+        let mut record = sample();
+        let missing = [JsonPatchOp::Replace {
+            path: "/nope".into(),
+            value: json!(1),
+        }];
+        assert!(apply_json_patch(&mut record, &missing).is_err());
+        let nested = [JsonPatchOp::Remove {
+            path: "/a/b".into(),
+        }];
+        assert!(matches!(
+            apply_json_patch(&mut record, &nested),
+            Err(ModelError::Parse(_))
+        ));
+
+        let ops: Vec<JsonPatchOp> = serde_json::from_value(json!([
+            {"op": "add", "path": "/a~1b", "value": 1},
+            {"op": "remove", "path": "/tmp"}
+        ]))
+        .unwrap();
+        apply_json_patch(&mut record, &ops).unwrap();
+        assert_eq!(record.get_value("a/b"), Some(&Value::Digit(1)));
+        assert!(record.field("tmp").is_none());
+    }
+}
@@ -1,13 +1,18 @@
 use crate::model::Maker;
+use crate::model::error::ModelError;
 use crate::model::format::LevelFormatAble;
-use crate::model::{DataType, FNameStr, FValueStr, Value};
+use crate::model::types::value::{json_field, value_to_json};
+use crate::model::{DataRecord, DataType, FNameStr, FValueStr, Value};
 use crate::traits::AsValueRef;
 use serde_derive::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::net::{IpAddr, Ipv4Addr};
 
 use super::field::Field;
+use indexmap::IndexMap;
 pub const WP_EVENT_ID: &str = "wp_event_id";
 /// 记录中每一项需要暴露的行为
 pub trait RecordItem {
@@ -17,6 +22,47 @@ pub trait RecordItem {
     fn get_value_mut(&mut self) -> &mut Value;
 }
 
+/// `RecordItem` 的便捷读取方法，按值类型取出内部值；类型不符时返回 `None`
+pub trait RecordItemExt: RecordItem {
+    fn is_type(&self, dt: &DataType) -> bool {
+        self.get_meta() == dt
+    }
+
+    fn digit_value(&self) -> Option<i64> {
+        match self.get_value() {
+            Value::Digit(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn chars_value(&self) -> Option<&str> {
+        match self.get_value() {
+            Value::Chars(v) => Some(v.as_str()),
+            _ => None,
+        }
+    }
+
+    fn bool_value(&self) -> Option<bool> {
+        match self.get_value() {
+            Value::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn float_value(&self) -> Option<f64> {
+        match self.get_value() {
+            Value::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn is_null_value(&self) -> bool {
+        matches!(self.get_value(), Value::Null)
+    }
+}
+
+impl<T: RecordItem> RecordItemExt for T {}
+
 /// 为 Record 生成字段所需的工厂方法
 pub trait RecordItemFactory {
     fn from_digit<S: Into<FNameStr>>(name: S, val: i64) -> Self;
@@ -24,6 +70,7 @@ pub trait RecordItemFactory {
     fn from_chars<N: Into<FNameStr>, Val: Into<FValueStr>>(name: N, val: Val) -> Self;
 }
 
+/// 派生的 serde 实现读写 `{"items": [...]}`；扁平 JSON 对象见 [`FlatRecord`]。
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Record<T> {
     pub items: Vec<T>,
@@ -102,6 +149,21 @@ impl<T> Record<T> {
     pub fn merge(&mut self, mut other: Self) {
         self.items.append(&mut other.items);
     }
+
+    /// 返回仅包含满足 `predicate` 的字段的新记录（保持原顺序），原记录不变
+    pub fn filter_fields<F: Fn(&T) -> bool>(&self, predicate: F) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            items: self
+                .items
+                .iter()
+                .filter(|x| predicate(x))
+                .cloned()
+                .collect(),
+        }
+    }
 }
 
 impl<T> Record<T>
@@ -128,6 +190,57 @@ where
             false
         }
     }
+
+    /// 按 `names` 的顺序投影字段，不存在的名称跳过；同名字段取第一个
+    pub fn select_fields(&self, names: &[&str]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            items: names
+                .iter()
+                .filter_map(|name| self.field(name).cloned())
+                .collect(),
+        }
+    }
+
+    /// `select_fields` 的补集：去掉名称在 `names` 中的所有字段，其余保持原顺序
+    pub fn exclude_fields(&self, names: &[&str]) -> Self
+    where
+        T: Clone,
+    {
+        self.filter_fields(|x| !names.contains(&x.get_name()))
+    }
+
+    /// 按字段顺序返回名称
+    pub fn field_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.items.iter().map(|x| x.get_name())
+    }
+
+    /// 按字段顺序返回值
+    pub fn values(&self) -> impl Iterator<Item = &Value> + '_ {
+        self.items.iter().map(|x| x.get_value())
+    }
+
+    /// 转为按名称索引的有序映射；同名字段保留第一个，与 [`Record::field`] 一致
+    pub fn into_map(self) -> IndexMap<String, T> {
+        let mut map = IndexMap::with_capacity(self.items.len());
+        for item in self.items {
+            if !map.contains_key(item.get_name()) {
+                map.insert(item.get_name().to_string(), item);
+            }
+        }
+        map
+    }
+
+    /// 同 [`Record::into_map`]，不消耗记录
+    pub fn to_map(&self) -> IndexMap<String, &T> {
+        let mut map = IndexMap::with_capacity(self.items.len());
+        for item in &self.items {
+            map.entry(item.get_name().to_string()).or_insert(item);
+        }
+        map
+    }
 }
 
 impl<V> RecordItem for Field<V>
@@ -168,8 +281,315 @@ where
     }
 }
 
+impl Record<Field<Value>> {
+    /// 根据字段类型生成 Avro record schema；`Ignore` 字段跳过，值为 `Null` 的字段生成 `["null", T]` 联合类型。
+    /// `Time` 与 JSON 输出一致按文本处理，映射为 `"string"`（Avro 的 `timestamp-millis` 只能标注 `long`）。
+    pub fn to_avro_schema(&self, namespace: &str, record_name: &str) -> serde_json::Value {
+        let fields: Vec<serde_json::Value> = self
+            .items
+            .iter()
+            .filter(|f| *f.get_meta() != DataType::Ignore)
+            .map(|f| {
+                let ty = avro_type(f.get_meta());
+                if matches!(f.get_value(), Value::Null) {
+                    serde_json::json!({ "name": f.get_name(), "type": ["null", ty], "default": null })
+                } else {
+                    serde_json::json!({ "name": f.get_name(), "type": ty })
+                }
+            })
+            .collect();
+        serde_json::json!({
+            "type": "record",
+            "name": record_name,
+            "namespace": namespace,
+            "fields": fields,
+        })
+    }
+
+    /// 转为以字段名为键的 JSON 对象，值规则同 `From<&Value> for serde_json::Value`；
+    /// `DataType::Ignore` 字段跳过，同名字段只保留第一个。
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let map = flat_fields(&self.items)
+            .map(|f| (f.get_name().to_string(), value_to_json(f.get_value())))
+            .collect();
+        serde_json::Value::Object(map)
+    }
+
+    /// 解析 JSON 对象文本为记录，字段顺序与 JSON 中一致，类型按值推断。
+    pub fn from_json_str(s: &str) -> Result<Self, ModelError> {
+        let v: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| ModelError::Parse(e.to_string()))?;
+        Self::from_json_value(v)
+    }
+
+    /// 已解析的 JSON 对象转为记录；非对象返回 `ModelError::Parse`。
+    pub fn from_json_value(v: serde_json::Value) -> Result<Self, ModelError> {
+        let serde_json::Value::Object(map) = v else {
+            return Err(ModelError::Parse(format!("expected JSON object, got: {v}")));
+        };
+        let items = map
+            .into_iter()
+            .map(|(k, v)| json_field(k.as_str(), v))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { items })
+    }
+
+    /// 按表头解析一行分隔文本（支持 RFC 4180 引号），每个值保存为 `Value::Chars` 字段；
+    /// 列数与表头不一致或引号未闭合时返回 `ModelError::Parse`。
+    pub fn from_csv_row(header: &[&str], row: &str, delimiter: char) -> Result<Self, ModelError> {
+        let cols = split_csv_row(row, delimiter)?;
+        if cols.len() != header.len() {
+            return Err(ModelError::Parse(format!(
+                "csv row has {} fields, header expects {}",
+                cols.len(),
+                header.len()
+            )));
+        }
+        let items = header
+            .iter()
+            .zip(cols)
+            .map(|(name, v)| Field::from_chars(*name, v))
+            .collect();
+        Ok(Self { items })
+    }
+
+    /// 同 [`Record::from_csv_row`]，并对每个值调用 `Value::normalize()` 推断类型。
+    pub fn from_csv_row_auto(
+        header: &[&str],
+        row: &str,
+        delimiter: char,
+    ) -> Result<Self, ModelError> {
+        let mut record = Self::from_csv_row(header, row, delimiter)?;
+        for field in record.items.iter_mut() {
+            let value = std::mem::replace(&mut field.value, Value::Null).normalize();
+            field.meta = DataType::infer_from_value(&value);
+            field.value = value;
+        }
+        Ok(record)
+    }
+
+    fn digits<'a>(&'a self, field: &'a str) -> impl Iterator<Item = i64> + 'a {
+        self.items
+            .iter()
+            .filter(move |f| f.get_name() == field)
+            .filter_map(|f| match f.get_value() {
+                Value::Digit(v) => Some(*v),
+                _ => None,
+            })
+    }
+
+    /// 同名 Digit 字段的最小值（merge 后可能存在多个同名字段）
+    pub fn min_digit(&self, field: &str) -> Option<i64> {
+        self.digits(field).min()
+    }
+
+    pub fn max_digit(&self, field: &str) -> Option<i64> {
+        self.digits(field).max()
+    }
+
+    /// 同名 Digit 字段求和；无匹配或溢出返回 `None`
+    pub fn sum_digit(&self, field: &str) -> Option<i64> {
+        let mut iter = self.digits(field);
+        let first = iter.next()?;
+        iter.try_fold(first, i64::checked_add)
+    }
+
+    /// 用 [`Value::add`] 依次累加所有同名字段的值；无匹配、类型不兼容或 Digit 溢出时返回 `None`
+    pub fn sum_field(&self, name: &str) -> Option<Value> {
+        let mut iter = self.items.iter().filter(|f| f.get_name() == name);
+        let first = iter.next()?.get_value().clone();
+        iter.try_fold(first, |acc, f| acc.add(f.get_value()))
+    }
+
+    /// 同名 Digit/Float 字段的平均值
+    pub fn mean_float(&self, field: &str) -> Option<f64> {
+        let (sum, cnt) = self
+            .items
+            .iter()
+            .filter(|f| f.get_name() == field)
+            .filter_map(|f| match f.get_value() {
+                Value::Digit(v) => Some(*v as f64),
+                Value::Float(v) => Some(*v),
+                _ => None,
+            })
+            .fold((0.0, 0usize), |(sum, cnt), v| (sum + v, cnt + 1));
+        (cnt > 0).then(|| sum / cnt as f64)
+    }
+
+    /// `to_avro_schema` 的字符串形式
+    pub fn to_avro_schema_str(
+        &self,
+        namespace: &str,
+        record_name: &str,
+    ) -> Result<String, ModelError> {
+        serde_json::to_string(&self.to_avro_schema(namespace, record_name))
+            .map_err(|e| ModelError::Format(e.to_string()))
+    }
+}
+
+/// 两条记录之间按字段名计算的差异（同名字段只取第一个），用于变更捕获（CDC）。
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RecordDiff {
+    /// 仅在新记录中出现的字段
+    pub added: Vec<Field<Value>>,
+    /// 仅在旧记录中出现的字段名
+    pub removed: Vec<String>,
+    /// 两边都存在但值不同的字段：`(name, old, new)`
+    pub changed: Vec<(String, Value, Value)>,
+}
+
+impl RecordDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// 将差异应用到 `record`：删除 `removed`，替换 `changed` 的值（类型按新值推断，
+    /// 新值为 `Null` 时保留原类型），并把 `added` 追加到末尾。
+    pub fn apply(&self, record: &mut DataRecord) {
+        for name in &self.removed {
+            record.remove_field(name);
+        }
+        for (name, _, new) in &self.changed {
+            if let Some(field) = record.get_value_mut(name) {
+                if !matches!(new, Value::Null) {
+                    field.meta = DataType::infer_from_value(new);
+                }
+                field.value = new.clone();
+            }
+        }
+        for field in &self.added {
+            record.append(field.clone());
+        }
+    }
+}
+
+impl Record<Field<Value>> {
+    /// 计算从 `self` 到 `other` 的差异；`added` 按 `other` 中的顺序，
+    /// `removed` / `changed` 按 `self` 中的顺序。
+    pub fn diff(&self, other: &DataRecord) -> RecordDiff {
+        let mine = self.to_map();
+        let theirs = other.to_map();
+        let mut diff = RecordDiff::default();
+        for (name, old) in &mine {
+            match theirs.get(name) {
+                None => diff.removed.push(name.clone()),
+                Some(new) if new.value != old.value => {
+                    diff.changed
+                        .push((name.clone(), old.value.clone(), new.value.clone()))
+                }
+                Some(_) => {}
+            }
+        }
+        diff.added = theirs
+            .iter()
+            .filter(|(name, _)| !mine.contains_key(*name))
+            .map(|(_, f)| (*f).clone())
+            .collect();
+        diff
+    }
+}
+
+/// 按字段值分组：键为第一个同名字段值的 `Display` 文本，缺少该字段的记录归入 `""` 组；
+/// 组内保持输入顺序。
+pub fn group_by_field(
+    records: &[DataRecord],
+    field_name: &str,
+) -> HashMap<String, Vec<DataRecord>> {
+    let mut groups: HashMap<String, Vec<DataRecord>> = HashMap::new();
+    for record in records {
+        let key = record
+            .field(field_name)
+            .map(|f| f.get_value().to_string())
+            .unwrap_or_default();
+        groups.entry(key).or_default().push(record.clone());
+    }
+    groups
+}
+
+/// 按 `predicate` 拆分为 `(满足, 不满足)` 两组，组内保持输入顺序
+pub fn partition_by<F: Fn(&DataRecord) -> bool>(
+    records: Vec<DataRecord>,
+    predicate: F,
+) -> (Vec<DataRecord>, Vec<DataRecord>) {
+    records.into_iter().partition(|r| predicate(r))
+}
+
+fn avro_type(meta: &DataType) -> serde_json::Value {
+    match meta {
+        DataType::Digit => serde_json::json!("long"),
+        DataType::Float => serde_json::json!("double"),
+        DataType::Bool => serde_json::json!("boolean"),
+        _ => serde_json::json!("string"),
+    }
+}
+
+/// 扁平 JSON 输出的字段：跳过 `DataType::Ignore`，同名字段只取第一个
+fn flat_fields(items: &[Field<Value>]) -> impl Iterator<Item = &Field<Value>> {
+    let mut seen = HashSet::new();
+    items
+        .iter()
+        .filter(move |f| *f.get_meta() != DataType::Ignore && seen.insert(f.get_name()))
+}
+
+/// 以扁平 JSON 对象 `{"name": value, ..}` 读写记录，字段规则同 [`Record::to_json_value`]。
+///
+/// 序列化接受 `FlatRecord(&record)`；反序列化为 `FlatRecord<DataRecord>`，规则同
+/// [`Record::from_json_value`]，可用于 `axum::Json<FlatRecord<DataRecord>>`。
+/// 读取经由 `serde_json::Value`，仅适用于自描述格式。
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatRecord<R>(pub R);
+
+impl<R: Borrow<DataRecord>> serde::Serialize for FlatRecord<R> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(None)?;
+        for f in flat_fields(&self.0.borrow().items) {
+            map.serialize_entry(f.get_name(), &value_to_json(f.get_value()))?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for FlatRecord<DataRecord> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let v = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+        DataRecord::from_json_value(v)
+            .map(FlatRecord)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 // ValueGetter impl removed from core; use function-style adapters in extension crates.
 
+fn split_csv_row(row: &str, delimiter: char) -> Result<Vec<String>, ModelError> {
+    let mut cols = Vec::new();
+    let mut cur = String::new();
+    let mut in_quotes = false;
+    let mut chars = row.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cur.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if cur.is_empty() => in_quotes = true,
+            c if c == delimiter && !in_quotes => cols.push(std::mem::take(&mut cur)),
+            _ => cur.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(ModelError::Parse(format!(
+            "unterminated quote in csv row: {row}"
+        )));
+    }
+    cols.push(cur);
+    Ok(cols)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +720,154 @@ mod tests {
         assert_eq!(record.items.len(), 2);
     }
 
+    #[test]
+    fn test_record_select_and_exclude_fields() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("a", 1),
+            Field::from_digit("b", 2),
+            Field::from_digit("c", 3),
+            Field::from_digit("d", 4),
+            Field::from_digit("e", 5),
+        ]);
+
+        let selected = record.select_fields(&["d", "a", "missing", "b"]);
+        let names: Vec<&str> = selected.items.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["d", "a", "b"]);
+        assert!(selected.field("c").is_none());
+        assert!(selected.field("e").is_none());
+
+        let excluded = record.exclude_fields(&["d", "a", "b"]);
+        let names: Vec<&str> = excluded.items.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["c", "e"]);
+        assert_eq!(record.items.len(), 5);
+    }
+
+    #[test]
+    fn test_record_filter_fields() {
+        let record = make_test_record();
+        let digits = record.filter_fields(|f| f.get_meta() == &DataType::Digit);
+        assert_eq!(digits.items.len(), 1);
+        assert_eq!(digits.items[0].get_name(), "age");
+        assert_eq!(record.items.len(), 3);
+    }
+
+    // ========== map conversion tests ==========
+
+    #[test]
+    fn test_record_to_map_and_into_map() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("z", 1),
+            Field::from_digit("a", 2),
+            Field::from_digit("z", 3),
+            Field::from_digit("m", 4),
+        ]);
+
+        let names: Vec<&str> = record.field_names().collect();
+        assert_eq!(names, vec!["z", "a", "z", "m"]);
+        let values: Vec<&Value> = record.values().collect();
+        assert_eq!(values[2], &Value::Digit(3));
+
+        let map = record.to_map();
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["z", "a", "m"]);
+        assert_eq!(map["z"].get_value(), &Value::Digit(1));
+
+        let map = record.into_map();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec!["z", "a", "m"]);
+        assert_eq!(map["z"].get_value(), &Value::Digit(1));
+        assert_eq!(map.get_index(2).unwrap().1.get_value(), &Value::Digit(4));
+    }
+
+    // ========== diff tests ==========
+
+    #[test]
+    fn test_record_diff_identical_is_empty() {
+        let record = DataRecord::test_value();
+        let diff = record.diff(&record.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff, RecordDiff::default());
+    }
+
+    #[test]
+    fn test_record_diff_added_removed_changed() {
+        let old: DataRecord = Record::from(vec![
+            Field::from_digit("id", 1),
+            Field::from_chars("name", "alice"),
+            Field::from_bool("tmp", true),
+        ]);
+        let new: DataRecord = Record::from(vec![
+            Field::from_digit("id", 1),
+            Field::from_chars("name", "bob"),
+            Field::from_digit("age", 30),
+        ]);
+
+        let diff = old.diff(&new);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.added, vec![Field::from_digit("age", 30)]);
+        assert_eq!(diff.removed, vec!["tmp".to_string()]);
+        assert_eq!(
+            diff.changed,
+            vec![("name".to_string(), Value::from("alice"), Value::from("bob"))]
+        );
+
+        let mut rebuilt = old.clone();
+        diff.apply(&mut rebuilt);
+        assert_eq!(rebuilt, new);
+        assert!(rebuilt.diff(&new).is_empty());
+    }
+
+    // ========== group_by_field tests ==========
+
+    #[test]
+    fn test_group_by_field() {
+        let rec = |session: Option<&str>, n: i64| {
+            let mut r = DataRecord::default();
+            if let Some(s) = session {
+                r.append(Field::from_chars("session_id", s));
+            }
+            r.append(Field::from_digit("n", n));
+            r
+        };
+        let records = vec![
+            rec(Some("a"), 1),
+            rec(Some("b"), 2),
+            rec(Some("a"), 3),
+            rec(Some("c"), 4),
+            rec(None, 5),
+            rec(Some("a"), 6),
+        ];
+
+        let groups = group_by_field(&records, "session_id");
+        assert_eq!(groups.len(), 4);
+        assert_eq!(groups["a"].len(), 3);
+        assert_eq!(groups["b"].len(), 1);
+        assert_eq!(groups["c"].len(), 1);
+        assert_eq!(groups[""].len(), 1);
+        assert_eq!(groups[""][0].get_value("n"), Some(&Value::Digit(5)));
+        let order: Vec<_> = groups["a"]
+            .iter()
+            .filter_map(|r| r.get_value("n"))
+            .collect();
+        assert_eq!(
+            order,
+            vec![&Value::Digit(1), &Value::Digit(3), &Value::Digit(6)]
+        );
+    }
+
+    #[test]
+    fn test_partition_by() {
+        let records: Vec<DataRecord> = (0..5)
+            .map(|i| Record::from(vec![Field::from_digit("n", i)]))
+            .collect();
+        let (even, odd) = partition_by(
+            records,
+            |r| matches!(r.get_value("n"), Some(Value::Digit(n)) if n % 2 == 0),
+        );
+        assert_eq!(even.len(), 3);
+        assert_eq!(odd.len(), 2);
+        assert_eq!(odd[0].get_value("n"), Some(&Value::Digit(1)));
+    }
+
     // ========== set_id tests ==========
 
     #[test]
@@ -349,6 +917,30 @@ mod tests {
         assert_eq!(field.get_value(), &Value::Digit(20));
     }
 
+    #[test]
+    fn test_record_item_ext_accessors() {
+        let n: DataField = Field::from_digit("n", 99);
+        assert_eq!(RecordItemExt::digit_value(&n), Some(99));
+        assert_eq!(n.chars_value(), None);
+        assert!(n.is_type(&DataType::Digit));
+        assert!(!n.is_null_value());
+
+        let s: DataField = Field::from_chars("s", "hi");
+        assert_eq!(s.chars_value(), Some("hi"));
+        assert_eq!(s.digit_value(), None);
+
+        let b: DataField = Field::from_bool("b", true);
+        assert_eq!(b.bool_value(), Some(true));
+        let f: DataField = Field::from_float("f", 1.5);
+        assert_eq!(f.float_value(), Some(1.5));
+
+        let null = DataField::new(DataType::Auto, "x", Value::Null);
+        assert!(null.is_null_value());
+
+        let shared = DataField::from_digit("n", 1).into_rc();
+        assert_eq!(shared.digit_value(), Some(1));
+    }
+
     // ========== RecordItemFactory trait tests ==========
 
     #[test]
@@ -366,6 +958,290 @@ mod tests {
         assert_eq!(chars.get_meta(), &DataType::Chars);
     }
 
+    // ========== Avro schema tests ==========
+
+    #[test]
+    fn test_record_to_avro_schema() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("id", 1),
+            Field::from_chars("name", "Alice"),
+            Field::new(DataType::Float, "score", Value::Float(9.5)),
+            Field::new(DataType::Chars, "nick", Value::Null),
+        ]);
+        let schema = record.to_avro_schema("com.example", "User");
+        assert_eq!(schema["type"], "record");
+        assert_eq!(schema["name"], "User");
+        assert_eq!(schema["namespace"], "com.example");
+
+        let fields = schema["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields[0]["type"], "long");
+        assert_eq!(fields[1]["type"], "string");
+        assert_eq!(fields[2]["type"], "double");
+        assert_eq!(fields[3]["type"], serde_json::json!(["null", "string"]));
+
+        let text = record.to_avro_schema_str("com.example", "User").unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, schema);
+    }
+
+    #[test]
+    fn test_record_to_avro_schema_time_and_ignore() {
+        let record: DataRecord = Record::from(vec![
+            Field::new(DataType::Time, "ts", Value::Null),
+            Field::new(DataType::Bool, "ok", Value::Bool(true)),
+            Field::new(DataType::Ignore, "skip", Value::Null),
+        ]);
+        let schema = record.to_avro_schema("ns", "Event");
+        let fields = schema["fields"].as_array().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0]["type"], serde_json::json!(["null", "string"]));
+        assert_eq!(fields[1]["type"], "boolean");
+    }
+
+    // ========== JSON parsing tests ==========
+
+    #[test]
+    fn test_record_from_json_str() {
+        let record = DataRecord::from_json_str(r#"{"ip":"1.2.3.4","count":42}"#).unwrap();
+        assert_eq!(record.items.len(), 2);
+        assert_eq!(record.items[0].get_name(), "ip");
+        assert_eq!(record.items[0].get_meta(), &DataType::IP);
+        assert_eq!(record.items[1].get_name(), "count");
+        assert_eq!(record.items[1].get_meta(), &DataType::Digit);
+        assert_eq!(record.get_value("count"), Some(&Value::Digit(42)));
+    }
+
+    #[test]
+    fn test_record_from_json_preserves_order_and_rejects_non_object() {
+        let record = DataRecord::from_json_str(r#"{"z":1,"a":"x","m":true}"#).unwrap();
+        let names: Vec<_> = record.items.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["z", "a", "m"]);
+
+        assert!(DataRecord::from_json_str("[1,2]").is_err());
+        assert!(DataRecord::from_json_str("{not json").is_err());
+        assert!(DataRecord::from_json_value(serde_json::json!(1)).is_err());
+    }
+
+    #[test]
+    fn test_record_to_json_value_round_trip() {
+        use crate::model::types::value::{ObjectValue, parse_sn};
+        use crate::model::{
+            DomainT, EmailT, HexT, IdCardT, IgnoreT, IpNetValue, MobilePhoneT, UrlValue,
+        };
+        use chrono::NaiveDate;
+
+        let mut obj = ObjectValue::new();
+        obj.insert("inner", DataField::from_digit("inner", 1));
+        let time = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap();
+        let ip: std::net::IpAddr = "10.0.0.1".parse().unwrap();
+        let values = vec![
+            Value::Null,
+            Value::Bool(true),
+            Value::from("text"),
+            Value::Float(1.5),
+            Value::Digit(42),
+            Value::Time(time),
+            Value::IpNet(IpNetValue::new(ip, 8).unwrap()),
+            Value::IpAddr(ip),
+            Value::Domain(DomainT("a.com".into())),
+            Value::Url(UrlValue("http://a.com".into())),
+            Value::Email(EmailT("a@b.c".into())),
+            Value::IdCard(IdCardT("110101".into())),
+            Value::MobilePhone(MobilePhoneT("13800000000".into())),
+            Value::SN(parse_sn("SN-1").unwrap()),
+            Value::Hex(HexT(255)),
+            Value::Obj(obj),
+            Value::Array(vec![DataField::from_digit("item", 1)]),
+            Value::Symbol("sym".into()),
+        ];
+        let mut record = DataRecord::default();
+        for (i, v) in values.into_iter().enumerate() {
+            record.append(DataField::new(
+                DataType::infer_from_value(&v),
+                format!("f{i}"),
+                v,
+            ));
+        }
+        record.append(DataField::new(
+            DataType::Ignore,
+            "skip",
+            Value::Ignore(IgnoreT {}),
+        ));
+
+        let json = record.to_json_value();
+        let obj = json.as_object().unwrap();
+        assert_eq!(obj.len(), 18);
+        assert!(!obj.contains_key("skip"));
+        assert_eq!(obj["f4"], serde_json::json!(42));
+        assert_eq!(obj["f15"], serde_json::json!({"inner": 1}));
+
+        let back = DataRecord::from_json_value(json.clone()).unwrap();
+        assert_eq!(back.to_json_value(), json);
+        for name in ["f0", "f1", "f2", "f3", "f4", "f7"] {
+            assert_eq!(back.get_value(name), record.get_value(name));
+        }
+    }
+
+    // ========== flat JSON serde tests ==========
+
+    #[test]
+    fn test_flat_record_serialize() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("user", "alice"));
+        record.append(DataField::from_digit("age", 30));
+        record.append(DataField::from_bool("active", true));
+        record.append(DataField::from_digit("age", 99));
+
+        let json = serde_json::to_string(&FlatRecord(&record)).unwrap();
+        assert_eq!(json, r#"{"user":"alice","age":30,"active":true}"#);
+    }
+
+    #[test]
+    fn test_flat_record_matches_to_json_value() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("user", "alice"));
+        record.append(DataField::new(
+            DataType::Ignore,
+            "skip",
+            Value::Ignore(Default::default()),
+        ));
+        record.append(DataField::from_digit("user", 2));
+
+        let flat = serde_json::to_value(FlatRecord(&record)).unwrap();
+        assert_eq!(flat, record.to_json_value());
+        assert_eq!(flat, serde_json::json!({"user": "alice"}));
+    }
+
+    #[test]
+    fn test_flat_record_deserialize() {
+        let FlatRecord(record): FlatRecord<DataRecord> =
+            serde_json::from_str(r#"{"user":"alice","age":30,"ip":"10.0.0.1"}"#).unwrap();
+        let names: Vec<_> = record.items.iter().map(|f| f.get_name()).collect();
+        assert_eq!(names, vec!["user", "age", "ip"]);
+        assert_eq!(record.get_value("age"), Some(&Value::Digit(30)));
+        assert_eq!(record.field("ip").unwrap().get_meta(), &DataType::IP);
+
+        let back = serde_json::to_value(FlatRecord(&record)).unwrap();
+        assert_eq!(
+            back,
+            serde_json::json!({"user": "alice", "age": 30, "ip": "10.0.0.1"})
+        );
+        assert!(serde_json::from_str::<FlatRecord<DataRecord>>("[1]").is_err());
+    }
+
+    #[test]
+    fn test_data_record_serde_roundtrip() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("user", "alice"));
+        record.append(DataField::from_digit("age", 30));
+        record.append(DataField::from_ip(
+            "ip",
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        ));
+
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.starts_with(r#"{"items":["#));
+        let back: DataRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, record);
+    }
+
+    // ========== CSV parsing tests ==========
+
+    #[test]
+    fn test_record_from_csv_row() {
+        let header = ["name", "msg", "n"];
+        let record = DataRecord::from_csv_row(&header, r#"bob,"a, ""b""",1"#, ',').unwrap();
+        assert_eq!(record.items.len(), 3);
+        assert_eq!(record.field("msg").unwrap().get_chars(), Some(r#"a, "b""#));
+        assert_eq!(record.items[2].get_meta(), &DataType::Chars);
+        assert_eq!(record.get_value("n"), Some(&Value::from("1")));
+
+        let record = DataRecord::from_csv_row(&["a", "b"], "x|", '|').unwrap();
+        assert_eq!(record.field("b").unwrap().get_chars(), Some(""));
+
+        assert!(DataRecord::from_csv_row(&header, "a,b", ',').is_err());
+        assert!(DataRecord::from_csv_row(&header, r#"a,"b,c"#, ',').is_err());
+    }
+
+    #[test]
+    fn test_record_from_csv_row_auto() {
+        let header = ["name", "age", "active"];
+        let record = DataRecord::from_csv_row_auto(&header, "alice,30,true", ',').unwrap();
+        let metas: Vec<_> = record.items.iter().map(|f| f.get_meta().clone()).collect();
+        assert_eq!(
+            metas,
+            vec![DataType::Chars, DataType::Digit, DataType::Bool]
+        );
+        assert_eq!(record.get_value("age"), Some(&Value::Digit(30)));
+        assert_eq!(record.get_value("active"), Some(&Value::Bool(true)));
+    }
+
+    // ========== aggregate tests ==========
+
+    #[test]
+    fn test_record_digit_aggregates() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("count", 1),
+            Field::from_chars("count", "n/a"),
+            Field::from_digit("count", 5),
+            Field::from_digit("other", 100),
+            Field::from_digit("count", 3),
+        ]);
+        assert_eq!(record.min_digit("count"), Some(1));
+        assert_eq!(record.max_digit("count"), Some(5));
+        assert_eq!(record.sum_digit("count"), Some(9));
+        assert_eq!(record.mean_float("count"), Some(3.0));
+
+        assert_eq!(record.min_digit("missing"), None);
+        assert_eq!(record.sum_digit("missing"), None);
+        assert_eq!(record.mean_float("missing"), None);
+    }
+
+    #[test]
+    fn test_record_aggregates_mixed_and_overflow() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("v", i64::MAX),
+            Field::from_digit("v", 1),
+            Field::new(DataType::Float, "v", Value::Float(2.0)),
+        ]);
+        assert_eq!(record.sum_digit("v"), None);
+        assert_eq!(record.max_digit("v"), Some(i64::MAX));
+
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("lat", 1),
+            Field::new(DataType::Float, "lat", Value::Float(2.0)),
+        ]);
+        assert_eq!(record.mean_float("lat"), Some(1.5));
+    }
+
+    #[test]
+    fn test_record_sum_field() {
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("v", 1),
+            Field::from_digit("other", 100),
+            Field::new(DataType::Float, "v", Value::Float(2.5)),
+            Field::from_chars("s", "ab"),
+            Field::from_chars("s", "cd"),
+        ]);
+        assert_eq!(record.sum_field("v"), Some(Value::Float(3.5)));
+        assert_eq!(record.sum_field("other"), Some(Value::Digit(100)));
+        assert_eq!(record.sum_field("s"), Some(Value::from("abcd")));
+        assert_eq!(record.sum_field("missing"), None);
+
+        let record: DataRecord = Record::from(vec![
+            Field::from_digit("v", i64::MAX),
+            Field::from_digit("v", 1),
+        ]);
+        assert_eq!(record.sum_field("v"), None);
+        let record: DataRecord =
+            Record::from(vec![Field::from_digit("v", 1), Field::from_chars("v", "x")]);
+        assert_eq!(record.sum_field("v"), None);
+    }
+
     // ========== Display test ==========
 
     #[test]
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::model::{DataRecord, Value};
+
+/// 单列统计结果，供 schema 探查使用
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColumnStats {
+    /// 字段缺失或值为 `Null` 的记录数
+    pub null_count: usize,
+    pub total_count: usize,
+    /// 非 `Null` 值的去重计数（按类型与文本区分，小基数时精确）
+    pub distinct_estimate: usize,
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+}
+
+/// 统计 `records` 中名为 `field` 的列（每条记录取第一个同名字段）；
+/// `min`/`max` 按 [`Value::total_cmp`] 比较，忽略 `Null`。
+pub fn column_stats(records: &[DataRecord], field: &str) -> ColumnStats {
+    let mut stats = ColumnStats {
+        total_count: records.len(),
+        ..ColumnStats::default()
+    };
+    let mut distinct: HashSet<(&str, String)> = HashSet::new();
+    for record in records {
+        let value = match record.get_value(field) {
+            None | Some(Value::Null) => {
+                stats.null_count += 1;
+                continue;
+            }
+            Some(v) => v,
+        };
+        distinct.insert((value.tag(), value.to_string()));
+        if stats
+            .min
+            .as_ref()
+            .is_none_or(|m| value.total_cmp(m).is_lt())
+        {
+            stats.min = Some(value.clone());
+        }
+        if stats
+            .max
+            .as_ref()
+            .is_none_or(|m| value.total_cmp(m).is_gt())
+        {
+            stats.max = Some(value.clone());
+        }
+    }
+    stats.distinct_estimate = distinct.len();
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DataField, DataType};
+
+    fn score_record(score: Option<i64>) -> DataRecord {
+        let mut record = DataRecord::default();
+        record.append(match score {
+            Some(v) => DataField::from_digit("score", v),
+            None => DataField::new(DataType::Digit, "score", Value::Null),
+        });
+        record
+    }
+
+    // ========== column_stats tests ==========
+
+    #[test]
+    fn test_column_stats_digits() {
+        let records: Vec<DataRecord> = [
+            Some(1),
+            Some(2),
+            Some(3),
+            None,
+            Some(4),
+            Some(5),
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+        ]
+        .into_iter()
+        .map(score_record)
+        .collect();
+
+        let stats = column_stats(&records, "score");
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.total_count, 10);
+        assert_eq!(stats.distinct_estimate, 5);
+        assert_eq!(stats.min, Some(Value::Digit(1)));
+        assert_eq!(stats.max, Some(Value::Digit(5)));
+    }
+
+    #[test]
+    fn test_column_stats_missing_and_empty() {
+        let mut other = DataRecord::default();
+        other.append(DataField::from_chars("name", "a"));
+        let records = vec![other, score_record(Some(7))];
+        let stats = column_stats(&records, "score");
+        assert_eq!(stats.null_count, 1);
+        assert_eq!(stats.distinct_estimate, 1);
+        assert_eq!(stats.min, stats.max);
+
+        assert_eq!(column_stats(&[], "score"), ColumnStats::default());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::model::DateTimeValue;
+use chrono::Local;
+
+use super::record::{Record, RecordItem};
+
+/// 带版本号与时间戳的记录，用于有状态流处理中区分同一记录的不同版本。
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionedRecord<T> {
+    pub record: Record<T>,
+    pub version: u64,
+    pub created_at: DateTimeValue,
+    pub updated_at: DateTimeValue,
+}
+
+impl<T> VersionedRecord<T> {
+    /// 版本号加一并将 `updated_at` 更新为当前本地时间
+    pub fn bump_version(&mut self) {
+        self.version += 1;
+        self.updated_at = Local::now().naive_local();
+    }
+}
+
+impl<T: RecordItem> VersionedRecord<T> {
+    /// 用 `field` 替换第一个同名字段（不存在时追加），并递增版本号。
+    pub fn with_field_update(&mut self, field: T) -> &mut Self {
+        match self
+            .record
+            .items
+            .iter_mut()
+            .find(|x| x.get_name() == field.get_name())
+        {
+            Some(slot) => *slot = field,
+            None => self.record.append(field),
+        }
+        self.bump_version();
+        self
+    }
+}
+
+/// 版本号从 0 开始，`created_at` 与 `updated_at` 均为当前本地时间。
+impl<T> From<Record<T>> for VersionedRecord<T> {
+    fn from(record: Record<T>) -> Self {
+        let now = Local::now().naive_local();
+        Self {
+            record,
+            version: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DataField, DataRecord, Value};
+
+    // ========== VersionedRecord tests ==========
+
+    #[test]
+    fn test_versioned_record_from_record() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("n", 1));
+        let versioned = VersionedRecord::from(record.clone());
+        assert_eq!(versioned.version, 0);
+        assert_eq!(versioned.created_at, versioned.updated_at);
+        assert_eq!(versioned.record, record);
+    }
+
+    #[test]
+    fn test_versioned_record_field_updates_bump_version() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("n", 1));
+        let mut versioned = VersionedRecord::from(record);
+
+        versioned
+            .with_field_update(DataField::from_digit("n", 2))
+            .with_field_update(DataField::from_chars("tag", "x"));
+        assert_eq!(versioned.version, 2);
+        assert!(versioned.updated_at >= versioned.created_at);
+        assert_eq!(versioned.record.items.len(), 2);
+        assert_eq!(versioned.record.get_value("n"), Some(&Value::Digit(2)));
+        assert_eq!(versioned.record.get_value("tag"), Some(&Value::from("x")));
+
+        versioned.bump_version();
+        assert_eq!(versioned.version, 3);
+    }
+}
@@ -9,3 +9,17 @@ pub enum ModelError {
     #[error("Data Model Validation error: {0}")]
     Validation(String),
 }
+
+/// `Value` 转换为具体 Rust 类型失败：记录期望类型与实际变体标签（[`crate::model::Value::tag`]）。
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("cannot convert {actual} value to {expected}")]
+pub struct ValueConvertError {
+    pub expected: &'static str,
+    pub actual: String,
+}
+
+impl From<ValueConvertError> for ModelError {
+    fn from(e: ValueConvertError) -> Self {
+        ModelError::Validation(e.to_string())
+    }
+}
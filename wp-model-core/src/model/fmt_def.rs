@@ -29,6 +29,15 @@ pub enum TextFmt {
     Proto,
     #[serde(rename = "proto-text")]
     ProtoText,
+    /// 换行分隔的 JSON（ndjson/jsonl）：每条记录为单行 JSON，行尾不带逗号
+    #[serde(rename = "ndjson")]
+    Ndjson,
+    /// 制表符分隔值，可选首行表头
+    #[serde(rename = "tsv")]
+    Tsv,
+    /// XML 文本，不做 schema 约束
+    #[serde(rename = "xml")]
+    Xml,
 }
 
 impl From<&str> for TextFmt {
@@ -45,6 +54,12 @@ impl From<&str> for TextFmt {
             TextFmt::Proto
         } else if value == "proto-text" {
             TextFmt::ProtoText
+        } else if value == "ndjson" || value == "jsonl" {
+            TextFmt::Ndjson
+        } else if value == "tsv" {
+            TextFmt::Tsv
+        } else if value == "xml" {
+            TextFmt::Xml
         } else {
             TextFmt::Raw
         }
@@ -61,6 +76,9 @@ impl Display for TextFmt {
             TextFmt::Raw => write!(f, "raw"),
             TextFmt::Proto => write!(f, "proto"),
             TextFmt::ProtoText => write!(f, "proto-text"),
+            TextFmt::Ndjson => write!(f, "ndjson"),
+            TextFmt::Tsv => write!(f, "tsv"),
+            TextFmt::Xml => write!(f, "xml"),
         }
     }
 }
@@ -84,6 +102,10 @@ mod tests {
         assert_eq!(TextFmt::from("kv"), TextFmt::Kv);
         assert_eq!(TextFmt::from("proto"), TextFmt::Proto);
         assert_eq!(TextFmt::from("proto-text"), TextFmt::ProtoText);
+        assert_eq!(TextFmt::from("ndjson"), TextFmt::Ndjson);
+        assert_eq!(TextFmt::from("jsonl"), TextFmt::Ndjson);
+        assert_eq!(TextFmt::from("tsv"), TextFmt::Tsv);
+        assert_eq!(TextFmt::from("xml"), TextFmt::Xml);
     }
 
     #[test]
@@ -92,6 +114,8 @@ mod tests {
         assert_eq!(TextFmt::from("unknown"), TextFmt::Raw);
         assert_eq!(TextFmt::from(""), TextFmt::Raw);
         assert_eq!(TextFmt::from("JSON"), TextFmt::Raw); // case sensitive
+        assert_eq!(TextFmt::from("NDJSON"), TextFmt::Raw);
+        assert_eq!(TextFmt::from("json-lines"), TextFmt::Raw);
     }
 
     #[test]
@@ -103,12 +127,25 @@ mod tests {
         assert_eq!(format!("{}", TextFmt::Raw), "raw");
         assert_eq!(format!("{}", TextFmt::Proto), "proto");
         assert_eq!(format!("{}", TextFmt::ProtoText), "proto-text");
+        assert_eq!(format!("{}", TextFmt::Ndjson), "ndjson");
+        assert_eq!(format!("{}", TextFmt::Tsv), "tsv");
+        assert_eq!(format!("{}", TextFmt::Xml), "xml");
     }
 
     #[test]
     fn test_text_fmt_roundtrip() {
         // from -> display should be consistent
-        let formats = ["json", "csv", "show", "kv", "proto", "proto-text"];
+        let formats = [
+            "json",
+            "csv",
+            "show",
+            "kv",
+            "proto",
+            "proto-text",
+            "ndjson",
+            "tsv",
+            "xml",
+        ];
         for fmt_str in formats {
             let fmt = TextFmt::from(fmt_str);
             assert_eq!(format!("{}", fmt), fmt_str);
@@ -151,6 +188,9 @@ mod tests {
             (TextFmt::Raw, "\"raw\""),
             (TextFmt::Proto, "\"proto\""),
             (TextFmt::ProtoText, "\"proto-text\""),
+            (TextFmt::Ndjson, "\"ndjson\""),
+            (TextFmt::Tsv, "\"tsv\""),
+            (TextFmt::Xml, "\"xml\""),
         ];
 
         for (fmt, expected_json) in variants {
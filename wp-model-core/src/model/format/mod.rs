@@ -1,5 +1,7 @@
 use std::fmt::Formatter;
 
+pub mod proto_text;
+
 pub struct MetaFmt<T>(pub T);
 
 pub struct FunFmt<T>(pub T);
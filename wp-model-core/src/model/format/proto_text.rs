@@ -0,0 +1,296 @@
+use crate::model::error::ModelError;
+use crate::model::types::value::ObjectValue;
+use crate::model::{DataField, DataRecord, DataType, Value};
+use std::fmt::{Display, Write};
+
+impl DataRecord {
+    /// 序列化为 protobuf 文本格式（`TextFmt::ProtoText`），每个字段一行 `name: value`。
+    ///
+    /// - 字符串加双引号并转义，`Digit`/`Float`/`Bool` 不加引号；
+    /// - `Obj` 输出为 `name { ... }`，`Array` 按 repeated 字段逐项输出；
+    /// - `Null`/`Ignore` 字段跳过，其余语义类型（IP、时间等）按文本输出为字符串。
+    pub fn to_proto_text(&self) -> String {
+        let mut out = String::new();
+        for field in &self.items {
+            // 写入 String 不会失败
+            let _ = write_field(&mut out, field.get_name(), field.get_value(), 0);
+        }
+        out
+    }
+
+    /// 解析 protobuf 文本格式：字符串 → `Chars`，整数 → `Digit`，浮点 → `Float`，
+    /// `true`/`false` → `Bool`，`name { ... }` → `Obj`；重复字段保留为同名多字段。
+    pub fn from_proto_text(s: &str) -> Result<Self, ModelError> {
+        let mut parser = Parser { src: s, pos: 0 };
+        let items = parser.fields(false)?;
+        Ok(Self { items })
+    }
+}
+
+fn write_field(out: &mut String, name: &str, value: &Value, level: usize) -> std::fmt::Result {
+    let indent = "  ".repeat(level);
+    match value {
+        Value::Null | Value::Ignore(_) => Ok(()),
+        Value::Obj(obj) => {
+            writeln!(out, "{indent}{name} {{")?;
+            for (key, field) in obj.iter() {
+                write_field(out, key, field.get_value(), level + 1)?;
+            }
+            writeln!(out, "{indent}}}")
+        }
+        Value::Array(items) => {
+            for item in items {
+                write_field(out, name, item.get_value(), level)?;
+            }
+            Ok(())
+        }
+        Value::Bool(v) => writeln!(out, "{indent}{name}: {v}"),
+        Value::Digit(v) => writeln!(out, "{indent}{name}: {v}"),
+        // Debug 输出总带小数点或指数，解析时不会误判为整数
+        Value::Float(v) => writeln!(out, "{indent}{name}: {v:?}"),
+        other => {
+            write!(out, "{indent}{name}: ")?;
+            match other.as_str_value() {
+                Some(s) => write_quoted(out, s),
+                None => write_quoted(out, &other.to_string()),
+            }
+            out.push('\n');
+            Ok(())
+        }
+    }
+}
+
+fn write_quoted(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// 跳过空白、`#` 注释以及字段间可选的 `,` / `;`
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.peek() {
+            match c {
+                '#' => while !matches!(self.bump(), None | Some('\n')) {},
+                c if c.is_whitespace() || c == ',' || c == ';' => {
+                    self.bump();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn error(&self, msg: impl Display) -> ModelError {
+        ModelError::Parse(format!("proto text at byte {}: {msg}", self.pos))
+    }
+
+    /// 解析字段序列：顶层读到输入结束，嵌套消息读到 `}`
+    fn fields(&mut self, nested: bool) -> Result<Vec<DataField>, ModelError> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_separators();
+            match self.peek() {
+                None if nested => return Err(self.error("unterminated message, expected '}'")),
+                None => return Ok(items),
+                Some('}') if nested => {
+                    self.bump();
+                    return Ok(items);
+                }
+                Some(_) => items.push(self.field()?),
+            }
+        }
+    }
+
+    fn field(&mut self) -> Result<DataField, ModelError> {
+        let name = self.word();
+        if name.is_empty() {
+            return Err(self.error("expected field name"));
+        }
+        self.skip_ws();
+        let has_colon = self.peek() == Some(':');
+        if has_colon {
+            self.bump();
+            self.skip_ws();
+        }
+        if self.peek() == Some('{') {
+            self.bump();
+            let mut obj = ObjectValue::new();
+            for field in self.fields(true)? {
+                obj.insert(field.name.clone(), field);
+            }
+            return Ok(DataField::from_obj(name, obj));
+        }
+        if !has_colon {
+            return Err(self.error(format!("expected ':' after field '{name}'")));
+        }
+        let value = self.scalar()?;
+        Ok(DataField::new(
+            DataType::infer_from_value(&value),
+            name,
+            value,
+        ))
+    }
+
+    fn word(&mut self) -> &'a str {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace()
+                || matches!(
+                    c,
+                    ':' | '{' | '}' | '[' | ']' | ',' | ';' | '#' | '"' | '\''
+                )
+            {
+                break;
+            }
+            self.bump();
+        }
+        &self.src[start..self.pos]
+    }
+
+    fn scalar(&mut self) -> Result<Value, ModelError> {
+        if let Some(quote @ ('"' | '\'')) = self.peek() {
+            self.bump();
+            return self.quoted(quote).map(|s| Value::Chars(s.into()));
+        }
+        let word = self.word();
+        if word.is_empty() {
+            return Err(self.error("expected value"));
+        }
+        Ok(match word {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            w => match w.parse::<i64>() {
+                Ok(v) => Value::Digit(v),
+                Err(_) => match w.parse::<f64>() {
+                    Ok(v) => Value::Float(v),
+                    // 未加引号的枚举名等按字符串处理
+                    Err(_) => Value::Chars(w.into()),
+                },
+            },
+        })
+    }
+
+    fn quoted(&mut self, quote: char) -> Result<String, ModelError> {
+        let mut out = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(self.error("unterminated string")),
+                Some(c) if c == quote => return Ok(out),
+                Some('\\') => match self.bump() {
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some(c) => out.push(c),
+                    None => return Err(self.error("unterminated string")),
+                },
+                Some(c) => out.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DataRecord {
+        let mut addr = ObjectValue::new();
+        addr.insert("city", DataField::from_chars("city", "Hang \"zhou\""));
+        addr.insert("zip", DataField::from_digit("zip", 310000));
+
+        let mut record = DataRecord::default();
+        record.append(DataField::from_chars("name", "alice"));
+        record.append(DataField::from_digit("age", 30));
+        record.append(DataField::from_bool("active", true));
+        record.append(DataField::from_obj("addr", addr));
+        record
+    }
+
+    // ========== to_proto_text tests ==========
+
+    #[test]
+    fn test_to_proto_text() {
+        let text = sample().to_proto_text();
+        assert_eq!(
+            text,
+            "name: \"alice\"\nage: 30\nactive: true\naddr {\n  city: \"Hang \\\"zhou\\\"\"\n  zip: 310000\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_to_proto_text_float_array_and_null() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_float("ratio", 2.0));
+        record.append(DataField::from_arr(
+            "ids",
+            vec![
+                DataField::from_digit("ids", 1),
+                DataField::from_digit("ids", 2),
+            ],
+        ));
+        record.append(DataField::new(DataType::Auto, "none", Value::Null));
+        assert_eq!(record.to_proto_text(), "ratio: 2.0\nids: 1\nids: 2\n");
+    }
+
+    // ========== from_proto_text tests ==========
+
+    #[test]
+    fn test_proto_text_roundtrip() {
+        let record = sample();
+        let parsed = DataRecord::from_proto_text(&record.to_proto_text()).unwrap();
+        assert_eq!(parsed, record);
+    }
+
+    #[test]
+    fn test_from_proto_text_syntax_variants() {
+        let text = "# comment\nid: -5, ratio: 1.5; level: INFO\nmsg: 'a\\tb'\nopt: {flag: false}";
+        let record = DataRecord::from_proto_text(text).unwrap();
+        assert_eq!(record.get_value("id"), Some(&Value::Digit(-5)));
+        assert_eq!(record.get_value("ratio"), Some(&Value::Float(1.5)));
+        assert_eq!(record.get_value("level"), Some(&Value::from("INFO")));
+        assert_eq!(record.get_value("msg"), Some(&Value::from("a\tb")));
+        let Some(Value::Obj(opt)) = record.get_value("opt") else {
+            panic!("expected object");
+        };
+        assert_eq!(opt.get("flag").unwrap().get_value(), &Value::Bool(false));
+    }
+
+    #[test]
+    fn test_from_proto_text_errors() {
+        assert!(DataRecord::from_proto_text("").unwrap().items.is_empty());
+        assert!(DataRecord::from_proto_text("name \"x\"").is_err());
+        assert!(DataRecord::from_proto_text("name: \"x").is_err());
+        assert!(DataRecord::from_proto_text("obj { a: 1").is_err());
+        assert!(DataRecord::from_proto_text("a:").is_err());
+    }
+}
@@ -1,91 +1,136 @@
+/// `value_match!` / `format_value!` 共用的逐变体展开
+#[doc(hidden)]
 #[macro_export]
-macro_rules! value_match {
-    ($obj:expr,$what :expr) => {
+macro_rules! __value_match_each {
+    ($obj:expr, |$x:ident| $body:expr) => {
         match $obj {
-            $crate::model::Value::Bool(x) => $what(x),
-            $crate::model::Value::Chars(x) => $what(x),
-            $crate::model::Value::Symbol(x) => $what(x.to_string()),
-            $crate::model::Value::Digit(x) => $what(x),
-            $crate::model::Value::Time(x) => $what(x),
-            $crate::model::Value::Hex(x) => $what(x),
-            $crate::model::Value::Float(x) => $what(x),
-            $crate::model::Value::IpNet(x) => $what(x),
-            $crate::model::Value::IpAddr(x) => $what(x),
-            $crate::model::Value::Ignore(x) => $what(x),
-            $crate::model::Value::Obj(x) => $what(x),
-            $crate::model::Value::Array(x) => $what(x),
-            $crate::model::Value::Domain(x) => $what(x),
-            $crate::model::Value::Url(x) => $what(x),
-            $crate::model::Value::Email(x) => $what(x),
-            $crate::model::Value::IdCard(x) => $what(x),
-            $crate::model::Value::MobilePhone(x) => $what(x),
+            $crate::model::Value::Bool($x) => $body,
+            $crate::model::Value::Chars($x) => $body,
+            $crate::model::Value::Symbol(s) => {
+                let $x = s.to_string();
+                $body
+            }
+            $crate::model::Value::Digit($x) => $body,
+            $crate::model::Value::Time($x) => $body,
+            $crate::model::Value::Duration($x) => $body,
+            $crate::model::Value::Hex($x) => $body,
+            $crate::model::Value::Uuid($x) => $body,
+            $crate::model::Value::Float($x) => $body,
+            $crate::model::Value::IpNet($x) => $body,
+            $crate::model::Value::IpAddr($x) => $body,
+            $crate::model::Value::MacAddr($x) => $body,
+            $crate::model::Value::Ignore($x) => $body,
+            $crate::model::Value::Obj($x) => $body,
+            $crate::model::Value::Array($x) => $body,
+            $crate::model::Value::Domain($x) => $body,
+            $crate::model::Value::Url($x) => $body,
+            $crate::model::Value::Email($x) => $body,
+            $crate::model::Value::IdCard($x) => $body,
+            $crate::model::Value::MobilePhone($x) => $body,
+            $crate::model::Value::SN($x) => $body,
         }
     };
+}
+
+#[macro_export]
+macro_rules! value_match {
+    ($obj:expr,$what :expr) => {
+        $crate::__value_match_each!($obj, |x| $what(x))
+    };
     ($obj:expr,$what :expr,$a1:expr) => {
-        match $obj {
-            $crate::model::Value::Bool(x) => $what(x, $a1),
-            $crate::model::Value::Chars(x) => $what(x, $a1),
-            $crate::model::Value::Symbol(x) => $what(x.to_string(), $a1),
-            $crate::model::Value::Digit(x) => $what(x, $a1),
-            $crate::model::Value::Time(x) => $what(x, $a1),
-            $crate::model::Value::Hex(x) => $what(x, $a1),
-            $crate::model::Value::Float(x) => $what(x, $a1),
-            $crate::model::Value::IpNet(x) => $what(x, $a1),
-            $crate::model::Value::IpAddr(x) => $what(x, $a1),
-            $crate::model::Value::Ignore(x) => $what(x, $a1),
-            $crate::model::Value::Obj(x) => $what(x, $a1),
-            $crate::model::Value::Array(x) => $what(x, $a1),
-            $crate::model::Value::Domain(x) => $what(x, $a1),
-            $crate::model::Value::Url(x) => $what(x, $a1),
-            $crate::model::Value::Email(x) => $what(x, $a1),
-            $crate::model::Value::IdCard(x) => $what(x, $a1),
-            $crate::model::Value::MobilePhone(x) => $what(x, $a1),
-        }
+        $crate::__value_match_each!($obj, |x| $what(x, $a1))
     };
     ($obj:expr,$what :expr,$a1:expr,$a2:expr) => {
-        match $obj {
-            $crate::model::Value::Bool(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Chars(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Symbol(x) => $what(x.to_string(), $a1, $a2),
-            $crate::model::Value::Digit(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Time(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Hex(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Float(x) => $what(x, $a1, $a2),
-            $crate::model::Value::IpNet(x) => $what(x, $a1, $a2),
-            $crate::model::Value::IpAddr(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Ignore(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Obj(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Array(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Domain(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Url(x) => $what(x, $a1, $a2),
-            $crate::model::Value::Email(x) => $what(x, $a1, $a2),
-            $crate::model::Value::IdCard(x) => $what(x, $a1, $a2),
-            $crate::model::Value::MobilePhone(x) => $what(x, $a1, $a2),
-        }
+        $crate::__value_match_each!($obj, |x| $what(x, $a1, $a2))
     };
 }
 
 #[macro_export]
 macro_rules! format_value {
     ($obj:expr,$what : ident ,$a1:expr) => {
+        $crate::__value_match_each!($obj, |x| $what(x).fmt($a1))
+    };
+}
+
+/// 对 `DataType` 的每个变体逐一展开匹配分支并调用处理器，新增变体时同步更新此宏，
+/// 以便在一处集中维护全部变体。
+///
+/// ```
+/// use wp_model_core::{datatype_match, model::DataType};
+/// assert_eq!(datatype_match!(DataType::Bool, |dt| dt.static_name()), "bool");
+/// ```
+#[macro_export]
+macro_rules! datatype_match {
+    ($obj:expr, |$dt:ident| $body:expr) => {
         match $obj {
-            $crate::model::Value::Bool(x) => $what(x).fmt($a1),
-            $crate::model::Value::Chars(x) => $what(x).fmt($a1),
-            $crate::model::Value::Symbol(x) => $what(x.to_string()).fmt($a1),
-            $crate::model::Value::Digit(x) => $what(x).fmt($a1),
-            $crate::model::Value::Time(x) => $what(x).fmt($a1),
-            $crate::model::Value::Hex(x) => $what(x).fmt($a1),
-            $crate::model::Value::Float(x) => $what(x).fmt($a1),
-            $crate::model::Value::IpNet(x) => $what(x).fmt($a1),
-            $crate::model::Value::IpAddr(x) => $what(x).fmt($a1),
-            $crate::model::Value::Ignore(x) => $what(x).fmt($a1),
-            $crate::model::Value::Obj(x) => $what(x).fmt($a1),
-            $crate::model::Value::Array(x) => $what(x).fmt($a1),
-            $crate::model::Value::Domain(x) => $what(x).fmt($a1),
-            $crate::model::Value::Url(x) => $what(x).fmt($a1),
-            $crate::model::Value::Email(x) => $what(x).fmt($a1),
-            $crate::model::Value::IdCard(x) => $what(x).fmt($a1),
-            $crate::model::Value::MobilePhone(x) => $what(x).fmt($a1),
+            $dt @ $crate::model::DataType::Bool => $body,
+            $dt @ $crate::model::DataType::Chars => $body,
+            $dt @ $crate::model::DataType::Symbol => $body,
+            $dt @ $crate::model::DataType::PeekSymbol => $body,
+            $dt @ $crate::model::DataType::Digit => $body,
+            $dt @ $crate::model::DataType::Float => $body,
+            $dt @ $crate::model::DataType::Ignore => $body,
+            $dt @ $crate::model::DataType::Time => $body,
+            $dt @ $crate::model::DataType::TimeISO => $body,
+            $dt @ $crate::model::DataType::TimeRFC3339 => $body,
+            $dt @ $crate::model::DataType::TimeRFC2822 => $body,
+            $dt @ $crate::model::DataType::TimeTIMESTAMP => $body,
+            $dt @ $crate::model::DataType::TimeCLF => $body,
+            $dt @ $crate::model::DataType::Duration => $body,
+            $dt @ $crate::model::DataType::IP => $body,
+            $dt @ $crate::model::DataType::IpNet => $body,
+            $dt @ $crate::model::DataType::MacAddr => $body,
+            $dt @ $crate::model::DataType::Domain => $body,
+            $dt @ $crate::model::DataType::Email => $body,
+            $dt @ $crate::model::DataType::Port => $body,
+            $dt @ $crate::model::DataType::SN => $body,
+            $dt @ $crate::model::DataType::Hex => $body,
+            $dt @ $crate::model::DataType::Base64 => $body,
+            $dt @ $crate::model::DataType::KV => $body,
+            $dt @ $crate::model::DataType::Json => $body,
+            $dt @ $crate::model::DataType::ExactJson => $body,
+            $dt @ $crate::model::DataType::HttpRequest => $body,
+            $dt @ $crate::model::DataType::HttpStatus => $body,
+            $dt @ $crate::model::DataType::HttpAgent => $body,
+            $dt @ $crate::model::DataType::HttpMethod => $body,
+            $dt @ $crate::model::DataType::Url => $body,
+            $dt @ $crate::model::DataType::Auto => $body,
+            $dt @ $crate::model::DataType::ProtoText => $body,
+            $dt @ $crate::model::DataType::Obj => $body,
+            $dt @ $crate::model::DataType::Array(_) => $body,
+            $dt @ $crate::model::DataType::IdCard => $body,
+            $dt @ $crate::model::DataType::MobilePhone => $body,
+            $dt @ $crate::model::DataType::Uuid => $body,
         }
     };
+    ($obj:expr, $what:expr) => {
+        $crate::datatype_match!($obj, |dt| $what(dt))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::DataType;
+
+    fn is_time(dt: &DataType) -> bool {
+        dt.static_name().starts_with("time")
+    }
+
+    // ========== datatype_match tests ==========
+
+    #[test]
+    fn test_datatype_match_closure() {
+        assert_eq!(
+            datatype_match!(DataType::Bool, |dt| dt.static_name()),
+            "bool"
+        );
+        let arr = DataType::Array("digit".into());
+        assert_eq!(datatype_match!(&arr, |dt| dt.to_string()), "array/digit");
+    }
+
+    #[test]
+    fn test_datatype_match_fn_path() {
+        assert!(datatype_match!(&DataType::TimeISO, is_time));
+        assert!(!datatype_match!(&DataType::IP, is_time));
+    }
 }
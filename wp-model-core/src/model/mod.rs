@@ -11,11 +11,16 @@ mod macros;
 // compare impls moved to orion_exp adapters
 //mod conv;
 pub mod data;
+pub mod schema;
 pub mod types;
 // conditions impls moved out; core remains pure types + format
 
-pub use types::meta::{DataType, MetaErr};
-pub use types::value::{DateTimeValue, DomainT, EmailT, IdCardT, Maker, MobilePhoneT, UrlValue};
+pub use data::record::RecordItemExt;
+pub use schema::{FieldSchema, RecordSchema};
+pub use types::meta::{DataCategory, DataType, MetaErr};
+pub use types::value::{
+    DateTimeValue, DomainT, EmailT, IdCardT, Maker, MobilePhoneT, SerialNumberT, UrlValue,
+};
 pub use types::value::{DigitValue, FloatValue, HexT, IgnoreT, IpNetValue};
 
 /// 字段名称类型
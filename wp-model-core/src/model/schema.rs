@@ -0,0 +1,155 @@
+use crate::model::error::ModelError;
+use crate::model::{DataRecord, DataType, Value};
+use serde::{Deserialize, Serialize};
+
+/// 单个字段的约束：类型、是否允许 `Value::Null`、是否必须出现
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldSchema {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub required: bool,
+}
+
+/// 记录级 schema，按字段名匹配（同名字段只校验第一个）
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct RecordSchema {
+    pub fields: Vec<FieldSchema>,
+}
+
+impl RecordSchema {
+    /// 从样例记录推断：每个字段均为 required，值为 `Null` 的字段标记为 nullable；
+    /// 同名字段只取第一个。
+    pub fn from_record(record: &DataRecord) -> Self {
+        let mut fields: Vec<FieldSchema> = Vec::with_capacity(record.items.len());
+        for item in &record.items {
+            if fields.iter().any(|f| f.name == item.get_name()) {
+                continue;
+            }
+            fields.push(FieldSchema {
+                name: item.get_name().to_string(),
+                data_type: item.get_meta().clone(),
+                nullable: matches!(item.get_value(), Value::Null),
+                required: true,
+            });
+        }
+        Self { fields }
+    }
+}
+
+/// 按 schema 校验记录，返回全部违规项（`ModelError::Validation`）：
+/// 缺少 required 字段、非 nullable 字段为 `Null`、字段类型与 `data_type` 不一致。
+/// schema 之外的字段不做检查；允许为 `Null` 的字段值为 `Null` 时跳过类型检查。
+pub fn validate(record: &DataRecord, schema: &RecordSchema) -> Result<(), Vec<ModelError>> {
+    let mut errors = Vec::new();
+    for expect in &schema.fields {
+        let Some(field) = record.field(&expect.name) else {
+            if expect.required {
+                errors.push(ModelError::Validation(format!(
+                    "missing required field '{}'",
+                    expect.name
+                )));
+            }
+            continue;
+        };
+        if matches!(field.get_value(), Value::Null) {
+            if !expect.nullable {
+                errors.push(ModelError::Validation(format!(
+                    "field '{}' must not be null",
+                    expect.name
+                )));
+            }
+            continue;
+        }
+        if field.get_meta() != &expect.data_type {
+            errors.push(ModelError::Validation(format!(
+                "field '{}' expected type {}, got {}",
+                expect.name,
+                expect.data_type,
+                field.get_meta()
+            )));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DataField;
+
+    fn field(name: &str, data_type: DataType, nullable: bool, required: bool) -> FieldSchema {
+        FieldSchema {
+            name: name.into(),
+            data_type,
+            nullable,
+            required,
+        }
+    }
+
+    fn schema() -> RecordSchema {
+        RecordSchema {
+            fields: vec![
+                field("id", DataType::Digit, false, true),
+                field("name", DataType::Chars, false, true),
+                field("note", DataType::Chars, true, false),
+            ],
+        }
+    }
+
+    // ========== validate tests ==========
+
+    #[test]
+    fn test_validate_ok() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("id", 1));
+        record.append(DataField::from_chars("name", "alice"));
+        record.append(DataField::new(DataType::Chars, "note", Value::Null));
+        record.append(DataField::from_bool("extra", true));
+        assert!(validate(&record, &schema()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_missing_required_field() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("id", 1));
+        let errors = validate(&record, &schema()).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].to_string().contains("'name'"));
+    }
+
+    #[test]
+    fn test_validate_null_and_type_mismatch() {
+        let mut record = DataRecord::default();
+        record.append(DataField::new(DataType::Digit, "id", Value::Null));
+        record.append(DataField::from_digit("name", 7));
+        let errors = validate(&record, &schema()).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].to_string().contains("must not be null"));
+        assert!(errors[1].to_string().contains("expected type"));
+    }
+
+    // ========== from_record tests ==========
+
+    #[test]
+    fn test_record_schema_from_record() {
+        let mut record = DataRecord::default();
+        record.append(DataField::from_digit("id", 1));
+        record.append(DataField::new(DataType::Chars, "note", Value::Null));
+        record.append(DataField::from_chars("id", "dup"));
+
+        let inferred = RecordSchema::from_record(&record);
+        assert_eq!(
+            inferred.fields,
+            vec![
+                field("id", DataType::Digit, false, true),
+                field("note", DataType::Chars, true, true),
+            ]
+        );
+        assert!(validate(&record, &inferred).is_ok());
+    }
+}
@@ -0,0 +1,142 @@
+use crate::model::DataField;
+use crate::model::Value;
+use crate::model::types::value::ObjectValue;
+
+/// 将 `DataType::KV` 文本（如 `k1=v1 k2="v 2"`）拆分为键值对。
+///
+/// - 值可以用 `"` 或 `'` 包裹，引号内的分隔符按普通字符处理；
+/// - `\` 转义下一个字符（包括分隔符与引号）；
+/// - `k=` 产生空值，缺少 `kv_sep` 的片段同样视为空值；
+/// - 键会去除首尾空白，空键片段被忽略。
+pub fn parse_kv_str(s: &str, pair_sep: char, kv_sep: char) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut key = String::new();
+    let mut val = String::new();
+    let mut in_value = false;
+    let mut quote: Option<char> = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        let cur = if in_value { &mut val } else { &mut key };
+        match c {
+            '\\' => cur.push(chars.next().unwrap_or('\\')),
+            q if quote == Some(q) => quote = None,
+            '"' | '\'' if quote.is_none() && cur.is_empty() => quote = Some(c),
+            c if quote.is_none() && c == pair_sep => {
+                flush_pair(&mut pairs, &mut key, &mut val);
+                in_value = false;
+            }
+            c if quote.is_none() && !in_value && c == kv_sep => in_value = true,
+            _ => cur.push(c),
+        }
+    }
+    flush_pair(&mut pairs, &mut key, &mut val);
+    pairs
+}
+
+fn flush_pair(pairs: &mut Vec<(String, String)>, key: &mut String, val: &mut String) {
+    let k = key.trim();
+    if !k.is_empty() {
+        pairs.push((k.to_string(), std::mem::take(val)));
+    }
+    key.clear();
+    val.clear();
+}
+
+/// 将键值对转换为 `ObjectValue`，每个值保存为 `Value::Chars` 字段；重复键以后者为准。
+pub fn kv_to_object_value(pairs: Vec<(String, String)>) -> ObjectValue {
+    let mut obj = ObjectValue::new();
+    for (k, v) in pairs {
+        let field = DataField::from_chars(k.as_str(), v);
+        obj.insert(k, field);
+    }
+    obj
+}
+
+/// 按 `k=v k=v` 约定解析 `Value::Chars` / `Value::Symbol`，其他类型返回 `None`。
+pub fn parse_kv_value(v: &Value) -> Option<ObjectValue> {
+    let text = match v {
+        Value::Chars(s) => s.as_str(),
+        Value::Symbol(s) => s.as_str(),
+        _ => return None,
+    };
+    Some(kv_to_object_value(parse_kv_str(text, ' ', '=')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DataType, FValueStr};
+
+    fn pair(k: &str, v: &str) -> (String, String) {
+        (k.to_string(), v.to_string())
+    }
+
+    // ========== parse_kv_str tests ==========
+
+    #[test]
+    fn test_parse_kv_str_basic() {
+        let pairs = parse_kv_str("k1=v1 k2=v2", ' ', '=');
+        assert_eq!(pairs, vec![pair("k1", "v1"), pair("k2", "v2")]);
+    }
+
+    #[test]
+    fn test_parse_kv_str_quoted_values() {
+        let pairs = parse_kv_str(r#"msg="hello world" user='a b' n=1"#, ' ', '=');
+        assert_eq!(
+            pairs,
+            vec![
+                pair("msg", "hello world"),
+                pair("user", "a b"),
+                pair("n", "1")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_kv_str_escaped_separators() {
+        let pairs = parse_kv_str(r"path=a\ b expr=x\=y", ' ', '=');
+        assert_eq!(pairs, vec![pair("path", "a b"), pair("expr", "x=y")]);
+    }
+
+    #[test]
+    fn test_parse_kv_str_empty_values() {
+        let pairs = parse_kv_str("a= b=2 flag", ' ', '=');
+        assert_eq!(pairs, vec![pair("a", ""), pair("b", "2"), pair("flag", "")]);
+    }
+
+    #[test]
+    fn test_parse_kv_str_custom_separators() {
+        let pairs = parse_kv_str("a:1, b:2,,c:3", ',', ':');
+        assert_eq!(pairs, vec![pair("a", "1"), pair("b", "2"), pair("c", "3")]);
+    }
+
+    #[test]
+    fn test_parse_kv_str_empty_input() {
+        assert!(parse_kv_str("", ' ', '=').is_empty());
+        assert!(parse_kv_str("   ", ' ', '=').is_empty());
+    }
+
+    // ========== ObjectValue conversion tests ==========
+
+    #[test]
+    fn test_kv_to_object_value() {
+        let obj = kv_to_object_value(vec![pair("k1", "v1"), pair("k2", "v2")]);
+        assert_eq!(obj.len(), 2);
+        let f = obj.get("k1").unwrap();
+        assert_eq!(f.get_name(), "k1");
+        assert_eq!(f.get_meta(), &DataType::Chars);
+        assert_eq!(f.get_value(), &Value::Chars(FValueStr::from("v1")));
+    }
+
+    #[test]
+    fn test_parse_kv_value() {
+        let obj = parse_kv_value(&Value::Chars("a=1 b=2".into())).unwrap();
+        assert_eq!(obj.get("b").unwrap().get_chars(), Some("2"));
+
+        let obj = parse_kv_value(&Value::Symbol("x=y".into())).unwrap();
+        assert_eq!(obj.get("x").unwrap().get_chars(), Some("y"));
+
+        assert!(parse_kv_value(&Value::Digit(1)).is_none());
+    }
+}
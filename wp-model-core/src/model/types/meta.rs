@@ -1,3 +1,4 @@
+use crate::model::Value;
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
@@ -29,6 +30,8 @@ pub enum DataType {
     TimeTIMESTAMP,
     #[serde(rename = "time_clf")]
     TimeCLF,
+    #[serde(rename = "duration")]
+    Duration,
     //#[serde(rename = "time_timestamp_ms)")]
     //TimeTimestampMs,
     //#[serde(rename = "time_timestamp_us)")]
@@ -37,6 +40,8 @@ pub enum DataType {
     IP,
     #[serde(rename = "ip_net")]
     IpNet,
+    #[serde(rename = "mac_addr")]
+    MacAddr,
     #[serde(rename = "domain")]
     Domain,
     #[serde(rename = "email")]
@@ -78,6 +83,8 @@ pub enum DataType {
     IdCard,
     #[serde(rename = "mobile_phone")]
     MobilePhone,
+    #[serde(rename = "uuid")]
+    Uuid,
 }
 
 pub const CHARS: &str = "chars";
@@ -90,8 +97,10 @@ pub const TIME_RFC3339: &str = "time_3339";
 pub const TIME_RFC2822: &str = "time_2822";
 pub const TIME_TIMESTAMP: &str = "time_timestamp";
 pub const TIME_CLF: &str = "time_clf";
+pub const DURATION: &str = "duration";
 pub const IP: &str = "ip";
 pub const IP_NET: &str = "ip_net";
+pub const MAC_ADDR: &str = "mac_addr";
 pub const DOMAIN: &str = "domain";
 pub const EMAIL: &str = "email";
 pub const SN: &str = "sn";
@@ -116,6 +125,18 @@ pub const OBJ: &str = "obj";
 pub const ARRAY: &str = "array";
 pub const ID_CARD: &str = "id_card";
 pub const MOBILE_PHONE: &str = "mobile_phone";
+pub const UUID: &str = "uuid";
+
+/// `DataType` 的粗粒度分类，供通用记录处理逻辑分派
+#[derive(Debug, PartialEq, Clone, Copy, Hash, Eq, Serialize, Deserialize)]
+pub enum DataCategory {
+    Numeric,
+    Temporal,
+    Network,
+    Text,
+    Composite,
+    Special,
+}
 
 #[derive(Error, Debug)]
 pub enum MetaErr {
@@ -145,8 +166,10 @@ impl DataType {
             TIME_RFC3339 => Ok(DataType::TimeRFC3339),
             TIME_RFC2822 => Ok(DataType::TimeRFC2822),
             TIME_TIMESTAMP => Ok(DataType::TimeTIMESTAMP),
+            DURATION => Ok(DataType::Duration),
             IP => Ok(DataType::IP),
             IP_NET => Ok(DataType::IpNet),
+            MAC_ADDR | "mac" => Ok(DataType::MacAddr),
             DOMAIN => Ok(DataType::Domain),
             EMAIL => Ok(DataType::Email),
             SN => Ok(DataType::SN),
@@ -172,6 +195,7 @@ impl DataType {
             OBJ => Ok(DataType::Obj),
             ID_CARD => Ok(DataType::IdCard),
             MOBILE_PHONE => Ok(DataType::MobilePhone),
+            UUID => Ok(DataType::Uuid),
             //ARRAY => Ok(Meta::Array),
             _ => Self::to_arr(value), //Err(MetaErr::UnSupport(format!("unknown meta: {}", value))),
         }
@@ -213,8 +237,10 @@ impl DataType {
             DataType::TimeRFC2822 => TIME_RFC2822,
             DataType::TimeTIMESTAMP => TIME_TIMESTAMP,
             DataType::TimeCLF => TIME_CLF,
+            DataType::Duration => DURATION,
             DataType::IP => IP,
             DataType::IpNet => IP_NET,
+            DataType::MacAddr => MAC_ADDR,
             DataType::Domain => DOMAIN,
             DataType::Email => EMAIL,
             DataType::SN => SN,
@@ -241,8 +267,189 @@ impl DataType {
             DataType::Array(_) => ARRAY,
             DataType::IdCard => ID_CARD,
             DataType::MobilePhone => MOBILE_PHONE,
+            DataType::Uuid => UUID,
+        }
+    }
+    /// 类型的英文说明，供配置编辑器与文档生成使用
+    pub fn description(&self) -> &'static str {
+        match self {
+            DataType::Bool => "boolean true/false",
+            DataType::Chars => "free-form UTF-8 text",
+            DataType::Symbol => "fixed symbol matched literally",
+            DataType::PeekSymbol => "symbol matched without consuming input",
+            DataType::Digit => "64-bit signed integer",
+            DataType::Float => "IEEE 754 double-precision float",
+            DataType::Ignore => "ignored field, not emitted",
+            DataType::Time => "date-time in common log layouts",
+            DataType::TimeISO => "ISO 8601 date-time",
+            DataType::TimeRFC3339 => "RFC 3339 date-time",
+            DataType::TimeRFC2822 => "RFC 2822 date-time",
+            DataType::TimeTIMESTAMP => "Unix epoch timestamp",
+            DataType::TimeCLF => "Common Log Format timestamp",
+            DataType::Duration => "time span with nanosecond precision",
+            DataType::IP => "IPv4 or IPv6 address",
+            DataType::IpNet => "IPv4 or IPv6 CIDR network",
+            DataType::MacAddr => "48-bit MAC hardware address",
+            DataType::Domain => "DNS domain name",
+            DataType::Email => "RFC 5321 email address",
+            DataType::Port => "TCP/UDP port number",
+            DataType::SN => "serial number or identifier token",
+            DataType::Hex => "hexadecimal number",
+            DataType::Base64 => "Base64-encoded payload",
+            DataType::KV => "key=value pairs",
+            DataType::Json => "JSON document, fields flattened",
+            DataType::ExactJson => "JSON document, parsed strictly",
+            DataType::HttpRequest => "HTTP request line",
+            DataType::HttpStatus => "HTTP status code",
+            DataType::HttpAgent => "HTTP User-Agent string",
+            DataType::HttpMethod => "HTTP request method",
+            DataType::Url => "URL (RFC 3986)",
+            DataType::Auto => "type inferred automatically",
+            DataType::ProtoText => "protobuf text format message",
+            DataType::Obj => "nested object of named fields",
+            DataType::Array(_) => "array of values of one subtype",
+            DataType::IdCard => "Chinese resident ID card number",
+            DataType::MobilePhone => "mobile phone number",
+            DataType::Uuid => "RFC 4122 UUID",
+        }
+    }
+
+    /// 该类型的典型示例值
+    pub fn example_value(&self) -> &'static str {
+        match self {
+            DataType::Bool => "true",
+            DataType::Chars => "hello world",
+            DataType::Symbol => "GET",
+            DataType::PeekSymbol => "[",
+            DataType::Digit => "42",
+            DataType::Float => "3.14",
+            DataType::Ignore => "-",
+            DataType::Time => "2024-01-15 10:30:00",
+            DataType::TimeISO => "2024-01-15T10:30:00",
+            DataType::TimeRFC3339 => "2024-01-15T10:30:00+08:00",
+            DataType::TimeRFC2822 => "Mon, 15 Jan 2024 10:30:00 +0800",
+            DataType::TimeTIMESTAMP => "1705285800",
+            DataType::TimeCLF => "15/Jan/2024:10:30:00 +0800",
+            DataType::Duration => "PT1.234S",
+            DataType::IP => "192.168.1.10",
+            DataType::IpNet => "192.168.1.0/24",
+            DataType::MacAddr => "AA:BB:CC:DD:EE:FF",
+            DataType::Domain => "example.com",
+            DataType::Email => "user@example.com",
+            DataType::Port => "8080",
+            DataType::SN => "SN-0001",
+            DataType::Hex => "0x1f",
+            DataType::Base64 => "aGVsbG8=",
+            DataType::KV => "k1=v1 k2=v2",
+            DataType::Json => r#"{"a":1}"#,
+            DataType::ExactJson => r#"{"a":{"b":2}}"#,
+            DataType::HttpRequest => "GET /index.html HTTP/1.1",
+            DataType::HttpStatus => "200",
+            DataType::HttpAgent => "Mozilla/5.0",
+            DataType::HttpMethod => "POST",
+            DataType::Url => "https://example.com/path?q=1",
+            DataType::Auto => "auto",
+            DataType::ProtoText => "name: \"a\" id: 1",
+            DataType::Obj => "{a: 1}",
+            DataType::Array(_) => "[1, 2, 3]",
+            DataType::IdCard => "11010519491231002X",
+            DataType::MobilePhone => "13800138000",
+            DataType::Uuid => "67e55044-10b1-426f-9247-bb680e5fe0c8",
         }
     }
+
+    /// 根据值的实际变体推断类型；`Null` 推断为 `Auto`，数组取首元素类型作为子类型。
+    pub fn infer_from_value(value: &Value) -> DataType {
+        match value {
+            Value::Null => DataType::Auto,
+            Value::Bool(_) => DataType::Bool,
+            Value::Chars(_) => DataType::Chars,
+            Value::Float(_) => DataType::Float,
+            Value::Digit(_) => DataType::Digit,
+            Value::Time(_) => DataType::Time,
+            Value::Duration(_) => DataType::Duration,
+            Value::IpNet(_) => DataType::IpNet,
+            Value::IpAddr(_) => DataType::IP,
+            Value::MacAddr(_) => DataType::MacAddr,
+            Value::Domain(_) => DataType::Domain,
+            Value::Url(_) => DataType::Url,
+            Value::Email(_) => DataType::Email,
+            Value::IdCard(_) => DataType::IdCard,
+            Value::MobilePhone(_) => DataType::MobilePhone,
+            Value::SN(_) => DataType::SN,
+            Value::Hex(_) => DataType::Hex,
+            Value::Uuid(_) => DataType::Uuid,
+            Value::Obj(_) => DataType::Obj,
+            Value::Array(items) => DataType::Array(
+                items
+                    .first()
+                    .map(|f| f.get_meta().to_string())
+                    .unwrap_or_else(|| "auto".into()),
+            ),
+            Value::Symbol(_) => DataType::Symbol,
+            Value::Ignore(_) => DataType::Ignore,
+        }
+    }
+
+    /// 类型分类：Digit/Float/Hex/Port 为 Numeric，`Time*` 与 Duration 为 Temporal，IP/MAC/域名/邮箱/URL/HTTP 为 Network，
+    /// 文本、结构化文本与 Uuid 为 Text，Obj/Array 为 Composite，其余（Bool、Ignore、Auto、Base64、IdCard、MobilePhone）为 Special。
+    pub fn category(&self) -> DataCategory {
+        match self {
+            DataType::Digit | DataType::Float | DataType::Hex | DataType::Port => {
+                DataCategory::Numeric
+            }
+            DataType::Time
+            | DataType::TimeISO
+            | DataType::TimeRFC3339
+            | DataType::TimeRFC2822
+            | DataType::TimeTIMESTAMP
+            | DataType::TimeCLF
+            | DataType::Duration => DataCategory::Temporal,
+            DataType::IP
+            | DataType::IpNet
+            | DataType::MacAddr
+            | DataType::Domain
+            | DataType::Email
+            | DataType::Url
+            | DataType::HttpRequest
+            | DataType::HttpStatus
+            | DataType::HttpAgent
+            | DataType::HttpMethod => DataCategory::Network,
+            DataType::Chars
+            | DataType::Symbol
+            | DataType::PeekSymbol
+            | DataType::SN
+            | DataType::Uuid
+            | DataType::KV
+            | DataType::Json
+            | DataType::ExactJson
+            | DataType::ProtoText => DataCategory::Text,
+            DataType::Obj | DataType::Array(_) => DataCategory::Composite,
+            DataType::Bool
+            | DataType::Ignore
+            | DataType::Auto
+            | DataType::Base64
+            | DataType::IdCard
+            | DataType::MobilePhone => DataCategory::Special,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.category() == DataCategory::Numeric
+    }
+
+    pub fn is_temporal(&self) -> bool {
+        self.category() == DataCategory::Temporal
+    }
+
+    pub fn is_network(&self) -> bool {
+        self.category() == DataCategory::Network
+    }
+
+    pub fn is_text(&self) -> bool {
+        self.category() == DataCategory::Text
+    }
+
     pub fn parse_patten_first(&self) -> bool {
         !matches!(
             self,
@@ -361,6 +568,12 @@ mod tests {
             DataType::from("mobile_phone").unwrap(),
             DataType::MobilePhone
         );
+        assert_eq!(DataType::from("uuid").unwrap(), DataType::Uuid);
+        assert_eq!(DataType::from("mac_addr").unwrap(), DataType::MacAddr);
+        assert_eq!(DataType::from("mac").unwrap(), DataType::MacAddr);
+        assert_eq!(DataType::from("duration").unwrap(), DataType::Duration);
+        assert_eq!(DataType::MacAddr.to_string(), "mac_addr");
+        assert_eq!(DataType::Uuid.to_string(), "uuid");
     }
 
     #[test]
@@ -461,6 +674,137 @@ mod tests {
         assert!(DataType::Time.parse_patten_first());
     }
 
+    #[test]
+    fn test_description_and_example() {
+        let all = [
+            DataType::Bool,
+            DataType::Chars,
+            DataType::Symbol,
+            DataType::PeekSymbol,
+            DataType::Digit,
+            DataType::Float,
+            DataType::Ignore,
+            DataType::Time,
+            DataType::TimeISO,
+            DataType::TimeRFC3339,
+            DataType::TimeRFC2822,
+            DataType::TimeTIMESTAMP,
+            DataType::TimeCLF,
+            DataType::IP,
+            DataType::IpNet,
+            DataType::Domain,
+            DataType::Email,
+            DataType::Port,
+            DataType::SN,
+            DataType::Hex,
+            DataType::Base64,
+            DataType::KV,
+            DataType::Json,
+            DataType::ExactJson,
+            DataType::HttpRequest,
+            DataType::HttpStatus,
+            DataType::HttpAgent,
+            DataType::HttpMethod,
+            DataType::Url,
+            DataType::Auto,
+            DataType::ProtoText,
+            DataType::Obj,
+            DataType::Array("digit".into()),
+            DataType::IdCard,
+            DataType::MobilePhone,
+            DataType::Uuid,
+            DataType::MacAddr,
+            DataType::Duration,
+        ];
+        let mut examples = std::collections::HashSet::new();
+        for dt in &all {
+            assert!(!dt.description().is_empty(), "{dt} has no description");
+            assert!(!dt.example_value().is_empty(), "{dt} has no example");
+            assert!(
+                examples.insert(dt.example_value()),
+                "{dt} example duplicated"
+            );
+        }
+        assert_eq!(DataType::Digit.description(), "64-bit signed integer");
+        assert_eq!(DataType::IpNet.example_value(), "192.168.1.0/24");
+    }
+
+    #[test]
+    fn test_category_covers_all_variants() {
+        use DataCategory::*;
+        let expected = [
+            (DataType::Bool, Special),
+            (DataType::Chars, Text),
+            (DataType::Symbol, Text),
+            (DataType::PeekSymbol, Text),
+            (DataType::Digit, Numeric),
+            (DataType::Float, Numeric),
+            (DataType::Ignore, Special),
+            (DataType::Time, Temporal),
+            (DataType::TimeISO, Temporal),
+            (DataType::TimeRFC3339, Temporal),
+            (DataType::TimeRFC2822, Temporal),
+            (DataType::TimeTIMESTAMP, Temporal),
+            (DataType::TimeCLF, Temporal),
+            (DataType::IP, Network),
+            (DataType::IpNet, Network),
+            (DataType::Domain, Network),
+            (DataType::Email, Network),
+            (DataType::Port, Numeric),
+            (DataType::SN, Text),
+            (DataType::Hex, Numeric),
+            (DataType::Base64, Special),
+            (DataType::KV, Text),
+            (DataType::Json, Text),
+            (DataType::ExactJson, Text),
+            (DataType::HttpRequest, Network),
+            (DataType::HttpStatus, Network),
+            (DataType::HttpAgent, Network),
+            (DataType::HttpMethod, Network),
+            (DataType::Url, Network),
+            (DataType::Auto, Special),
+            (DataType::ProtoText, Text),
+            (DataType::Obj, Composite),
+            (DataType::Array("digit".into()), Composite),
+            (DataType::IdCard, Special),
+            (DataType::MobilePhone, Special),
+            (DataType::Uuid, Text),
+            (DataType::MacAddr, Network),
+            (DataType::Duration, Temporal),
+        ];
+        for (dt, category) in &expected {
+            assert_eq!(dt.category(), *category, "{dt}");
+            assert_eq!(dt.is_numeric(), *category == Numeric, "{dt}");
+            assert_eq!(dt.is_temporal(), *category == Temporal, "{dt}");
+            assert_eq!(dt.is_network(), *category == Network, "{dt}");
+            assert_eq!(dt.is_text(), *category == Text, "{dt}");
+        }
+    }
+
+    #[test]
+    fn test_infer_from_value() {
+        use crate::model::DataField;
+        use std::net::{IpAddr, Ipv4Addr};
+        assert_eq!(
+            DataType::infer_from_value(&Value::Digit(1)),
+            DataType::Digit
+        );
+        assert_eq!(
+            DataType::infer_from_value(&Value::IpAddr(IpAddr::V4(Ipv4Addr::LOCALHOST))),
+            DataType::IP
+        );
+        assert_eq!(DataType::infer_from_value(&Value::Null), DataType::Auto);
+        let arr = Value::Array(vec![DataField::from_chars("item", "x")]);
+        assert_eq!(
+            DataType::infer_from_value(&arr),
+            DataType::Array("chars".into())
+        );
+        assert_eq!(
+            DataType::infer_from_value(&Value::Array(vec![])),
+            DataType::Array("auto".into())
+        );
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(DataType::default(), DataType::Auto);
@@ -1,2 +1,9 @@
+pub mod kv;
 pub mod meta;
+pub mod validators;
 pub mod value;
+
+pub use validators::{
+    HttpMethod, parse_http_method, parse_http_request_line, parse_http_status_line,
+    validate_base64, validate_exact_json, validate_json_schema,
+};
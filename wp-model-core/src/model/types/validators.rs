@@ -0,0 +1,262 @@
+use serde_json::Value as JsonValue;
+
+/// 校验 `DataType::ExactJson` 文本，成功时返回解析后的 JSON；错误信息包含行列位置。
+pub fn validate_exact_json(s: &str) -> Result<JsonValue, String> {
+    serde_json::from_str(s).map_err(|e| format!("invalid json: {e}"))
+}
+
+/// 按最小 JSON Schema 子集校验：仅检查 `type`（字符串或字符串数组），
+/// 并递归进入 `properties` 与 `items`。返回全部不匹配项，路径以 `$` 为根。
+pub fn validate_json_schema(value: &JsonValue, schema: &JsonValue) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+    check_schema(value, schema, "$", &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check_schema(value: &JsonValue, schema: &JsonValue, path: &str, errors: &mut Vec<String>) {
+    let expected: Vec<&str> = match schema.get("type") {
+        Some(JsonValue::String(t)) => vec![t.as_str()],
+        Some(JsonValue::Array(ts)) => ts.iter().filter_map(JsonValue::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !expected.is_empty() && !expected.iter().any(|t| type_matches(value, t)) {
+        errors.push(format!(
+            "{path}: expected {}, got {}",
+            expected.join("|"),
+            json_type_name(value)
+        ));
+        return;
+    }
+    if let (JsonValue::Object(obj), Some(JsonValue::Object(props))) =
+        (value, schema.get("properties"))
+    {
+        for (key, sub) in props {
+            if let Some(v) = obj.get(key) {
+                check_schema(v, sub, &format!("{path}.{key}"), errors);
+            }
+        }
+    }
+    if let (JsonValue::Array(items), Some(sub)) = (value, schema.get("items")) {
+        for (idx, v) in items.iter().enumerate() {
+            check_schema(v, sub, &format!("{path}[{idx}]"), errors);
+        }
+    }
+}
+
+fn type_matches(value: &JsonValue, ty: &str) -> bool {
+    match ty {
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        other => json_type_name(value) == other,
+    }
+}
+
+fn json_type_name(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+/// 校验 `DataType::Base64` 文本（标准字母表，`=` 填充可省略）并返回解码后的字节。
+pub fn validate_base64(s: &str) -> Result<Vec<u8>, String> {
+    let body = s
+        .strip_suffix("==")
+        .or_else(|| s.strip_suffix('='))
+        .unwrap_or(s);
+    let (len_rem, body_rem) = (s.len() % 4, body.len() % 4);
+    if body.len() != s.len() && len_rem != 0 {
+        return Err("invalid base64 padding".into());
+    }
+    if body_rem == 1 {
+        return Err(format!("invalid base64 length: {}", s.len()));
+    }
+    let mut out = Vec::with_capacity(body.len() * 3 / 4);
+    let mut buf = 0u32;
+    let mut bits = 0;
+    for (idx, b) in body.bytes().enumerate() {
+        let v = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(format!("invalid base64 character at {idx}")),
+        };
+        buf = (buf << 6) | u32::from(v);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buf >> bits) as u8);
+            buf &= (1 << bits) - 1;
+        }
+    }
+    Ok(out)
+}
+
+/// `DataType::HttpMethod` 取值
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+    Head,
+    Options,
+    Connect,
+    Trace,
+}
+
+/// 解析 HTTP 方法名（区分大小写，如 `GET`）。
+pub fn parse_http_method(s: &str) -> Option<HttpMethod> {
+    Some(match s {
+        "GET" => HttpMethod::Get,
+        "POST" => HttpMethod::Post,
+        "PUT" => HttpMethod::Put,
+        "DELETE" => HttpMethod::Delete,
+        "PATCH" => HttpMethod::Patch,
+        "HEAD" => HttpMethod::Head,
+        "OPTIONS" => HttpMethod::Options,
+        "CONNECT" => HttpMethod::Connect,
+        "TRACE" => HttpMethod::Trace,
+        _ => return None,
+    })
+}
+
+/// 解析 `DataType::HttpRequest` 请求行，如 `"GET /api/v1/users HTTP/1.1"` →
+/// `("GET", "/api/v1/users", "HTTP/1.1")`；方法须为 [`HttpMethod`] 之一，版本须以 `HTTP/` 开头。
+pub fn parse_http_request_line(s: &str) -> Option<(String, String, String)> {
+    let mut parts = s.split_whitespace();
+    let (method, path, version) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || !version.starts_with("HTTP/") {
+        return None;
+    }
+    parse_http_method(method)?;
+    Some((method.to_string(), path.to_string(), version.to_string()))
+}
+
+/// 解析 `DataType::HttpStatus` 状态行，如 `"200 OK"` → `(200, "OK")`；
+/// 允许前缀 `HTTP/x.y`，原因短语可为空或包含空格，状态码须在 100..=599。
+pub fn parse_http_status_line(s: &str) -> Option<(u16, String)> {
+    let mut rest = s.trim();
+    if rest.starts_with("HTTP/") {
+        rest = rest.split_once(' ')?.1.trim_start();
+    }
+    let (code, reason) = rest.split_once(' ').unwrap_or((rest, ""));
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let code: u16 = code.parse().ok()?;
+    if !(100..=599).contains(&code) {
+        return None;
+    }
+    Some((code, reason.trim().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // ========== ExactJson tests ==========
+
+    #[test]
+    fn test_validate_exact_json() {
+        let v = validate_exact_json(r#"{"a":[1,2]}"#).unwrap();
+        assert_eq!(v, json!({"a": [1, 2]}));
+
+        let err = validate_exact_json(r#"{"a":[1,"#).unwrap_err();
+        assert!(!err.is_empty());
+        assert!(validate_exact_json("").is_err());
+    }
+
+    // ========== JSON schema tests ==========
+
+    #[test]
+    fn test_validate_json_schema_types() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer"},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "note": {"type": ["string", "null"]}
+            }
+        });
+        let ok = json!({"id": 1, "tags": ["a"], "note": null, "extra": true});
+        assert!(validate_json_schema(&ok, &schema).is_ok());
+
+        let bad = json!({"id": 1.5, "tags": ["a", 2]});
+        let errors = validate_json_schema(&bad, &schema).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "$.id: expected integer, got number".to_string(),
+                "$.tags[1]: expected string, got number".to_string(),
+            ]
+        );
+
+        let errors = validate_json_schema(&json!([1]), &schema).unwrap_err();
+        assert_eq!(errors, vec!["$: expected object, got array".to_string()]);
+        assert!(validate_json_schema(&json!("x"), &json!({})).is_ok());
+    }
+
+    // ========== HTTP tests ==========
+
+    #[test]
+    fn test_parse_http_request_line() {
+        assert_eq!(
+            parse_http_request_line("GET /api/v1/users HTTP/1.1"),
+            Some((
+                "GET".to_string(),
+                "/api/v1/users".to_string(),
+                "HTTP/1.1".to_string()
+            ))
+        );
+        assert!(parse_http_request_line("GET /api/v1/users").is_none());
+        assert!(parse_http_request_line("FETCH / HTTP/1.1").is_none());
+        assert!(parse_http_request_line("GET / FTP/1.0").is_none());
+        assert!(parse_http_request_line("GET / HTTP/1.1 extra").is_none());
+    }
+
+    #[test]
+    fn test_parse_http_status_line() {
+        assert_eq!(parse_http_status_line("200 OK"), Some((200, "OK".into())));
+        assert_eq!(
+            parse_http_status_line("HTTP/1.1 404 Not Found"),
+            Some((404, "Not Found".into()))
+        );
+        assert_eq!(parse_http_status_line("204"), Some((204, String::new())));
+        assert!(parse_http_status_line("42 OK").is_none());
+        assert!(parse_http_status_line("700 Nope").is_none());
+        assert!(parse_http_status_line("OK").is_none());
+    }
+
+    #[test]
+    fn test_parse_http_method() {
+        assert_eq!(parse_http_method("PATCH"), Some(HttpMethod::Patch));
+        assert_eq!(parse_http_method("TRACE"), Some(HttpMethod::Trace));
+        assert!(parse_http_method("get").is_none());
+    }
+
+    // ========== Base64 tests ==========
+
+    #[test]
+    fn test_validate_base64() {
+        assert_eq!(validate_base64("aGVsbG8=").unwrap(), b"hello");
+        assert_eq!(validate_base64("aGVsbG8").unwrap(), b"hello");
+        assert_eq!(validate_base64("aGk=").unwrap(), b"hi");
+        assert_eq!(validate_base64("").unwrap(), b"");
+        assert!(validate_base64("aGVsbG8*").is_err());
+        assert!(validate_base64("aGVsb").is_err());
+        assert!(validate_base64("aGk==").is_err());
+    }
+}
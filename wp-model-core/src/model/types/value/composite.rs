@@ -1,6 +1,7 @@
-use crate::model::DataField;
+use crate::model::error::ModelError;
+use crate::model::{DataField, DataType};
 
-use super::Value;
+use super::{Value, json_field, value_to_json};
 use smol_str::SmolStr;
 use std::{
     collections::BTreeMap,
@@ -42,6 +43,138 @@ impl ObjectValue {
     pub fn get_mut(&mut self, key: &str) -> Option<&mut DataField> {
         self.0.get_mut(key)
     }
+
+    /// 键不存在时插入 `default`，返回该键对应字段的可变引用；已存在时不覆盖。
+    pub fn get_or_insert<'a>(&'a mut self, key: &str, default: DataField) -> &'a mut DataField {
+        self.0.entry(SmolStr::from(key)).or_insert(default)
+    }
+
+    /// 同 [`ObjectValue::get_or_insert`]，默认值仅在键缺失时构造。
+    pub fn get_or_insert_with<'a, F: FnOnce() -> DataField>(
+        &'a mut self,
+        key: &str,
+        f: F,
+    ) -> &'a mut DataField {
+        self.0.entry(SmolStr::from(key)).or_insert_with(f)
+    }
+
+    /// 键缺失时以 `DataField::new(meta, key, default_value)` 插入。
+    pub fn get_or_default<'a>(
+        &'a mut self,
+        key: &str,
+        meta: DataType,
+        default_value: Value,
+    ) -> &'a mut DataField {
+        self.get_or_insert_with(key, || DataField::new(meta, key, default_value))
+    }
+
+    /// 按 `.` 分隔的路径写入字段，缺失的中间层自动创建为 `Value::Obj`。
+    ///
+    /// 新建了路径（中间层或叶子）返回 `true`，仅更新已有叶子返回 `false`；
+    /// 路径为空、含空段，或中间层已存在但不是对象时不做修改并返回 `false`。
+    pub fn set_path_create(&mut self, path: &str, field: DataField) -> bool {
+        self.put_path(path, field).unwrap_or(false)
+    }
+
+    /// 同 [`ObjectValue::set_path_create`]，失败时返回原因：
+    /// 路径为空或含空段为 `ModelError::Parse`，中间层不是对象为 `ModelError::Validation`。
+    pub fn insert_nested(&mut self, path: &str, field: DataField) -> Result<(), ModelError> {
+        self.put_path(path, field).map(|_| ())
+    }
+
+    /// 按 `.` 分隔的路径逐层查找，任一层缺失或不是对象时返回 `None`。
+    pub fn get_nested(&self, path: &str) -> Option<&Value> {
+        let (leaf, parents) = path
+            .rsplit_once('.')
+            .map_or((path, None), |(p, l)| (l, Some(p)));
+        let mut cur = self;
+        for seg in parents.into_iter().flat_map(|p| p.split('.')) {
+            match cur.get(seg).map(|f| &f.value) {
+                Some(Value::Obj(obj)) => cur = obj,
+                _ => return None,
+            }
+        }
+        cur.get(leaf).map(|f| &f.value)
+    }
+
+    /// 深度合并：仅 `other` 中存在的键直接加入；两边都存在时，
+    /// 若均为 `Value::Obj` 则递归合并，否则以 `other` 为准。
+    pub fn deep_merge(&mut self, other: ObjectValue) {
+        for (key, field) in other.0 {
+            match (self.0.get_mut(&key), field.value) {
+                (
+                    Some(DataField {
+                        value: Value::Obj(mine),
+                        ..
+                    }),
+                    Value::Obj(theirs),
+                ) => mine.deep_merge(theirs),
+                (_, value) => {
+                    self.0.insert(key, DataField { value, ..field });
+                }
+            }
+        }
+    }
+
+    fn put_path(&mut self, path: &str, field: DataField) -> Result<bool, ModelError> {
+        let segs: Vec<&str> = path.split('.').collect();
+        if segs.iter().any(|s| s.is_empty()) {
+            return Err(ModelError::Parse(format!("invalid nested path '{path}'")));
+        }
+        let Some((leaf, parents)) = segs.split_last() else {
+            return Err(ModelError::Parse(format!("invalid nested path '{path}'")));
+        };
+        let mut cur = self;
+        let mut created = false;
+        for seg in parents {
+            if !cur.contains_key(*seg) {
+                cur.insert(*seg, DataField::from_obj(*seg, ObjectValue::new()));
+                created = true;
+            }
+            match cur.get_mut(seg).map(|f| &mut f.value) {
+                Some(Value::Obj(obj)) => cur = obj,
+                _ => {
+                    return Err(ModelError::Validation(format!(
+                        "'{seg}' in path '{path}' is not an object"
+                    )));
+                }
+            }
+        }
+        let replaced = cur.0.insert(SmolStr::from(*leaf), field);
+        Ok(created || replaced.is_none())
+    }
+}
+
+/// JSON 对象转换为 `ObjectValue`，各字段按 `TryFrom<serde_json::Value> for Value` 转换，
+/// 类型由 [`DataType::infer_from_value`] 推断；非对象返回 `ModelError::Parse`。
+impl TryFrom<serde_json::Value> for ObjectValue {
+    type Error = ModelError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        let serde_json::Value::Object(map) = value else {
+            return Err(ModelError::Parse(format!(
+                "expected json object, got: {value}"
+            )));
+        };
+        let mut obj = ObjectValue::new();
+        for (k, v) in map {
+            let field = json_field(k.as_str(), v)?;
+            obj.insert(k, field);
+        }
+        Ok(obj)
+    }
+}
+
+/// 输出为 `serde_json::Value::Object`：`Digit`/`Float`/`Bool` 为 JSON 原生类型，
+/// `Null`/`Ignore` 及非有限浮点为 `null`，其余类型按文本输出为字符串。
+impl From<&ObjectValue> for serde_json::Value {
+    fn from(obj: &ObjectValue) -> Self {
+        serde_json::Value::Object(
+            obj.iter()
+                .map(|(k, f)| (k.to_string(), value_to_json(f.get_value())))
+                .collect(),
+        )
+    }
 }
 
 impl Display for ObjectValue {
@@ -63,3 +196,204 @@ impl DerefMut for ObjectValue {
     }
 }
 use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested<'a>(obj: &'a ObjectValue, key: &str) -> &'a ObjectValue {
+        match obj.get(key).map(|f| &f.value) {
+            Some(Value::Obj(o)) => o,
+            other => panic!("expected object at {key}, got {other:?}"),
+        }
+    }
+
+    // ========== get_or_insert tests ==========
+
+    #[test]
+    fn test_get_or_insert_keeps_existing_entry() {
+        let mut obj = ObjectValue::new();
+        let f = obj.get_or_insert("n", DataField::from_digit("n", 1));
+        assert_eq!(f.get_value(), &Value::Digit(1));
+        f.value = Value::Digit(5);
+
+        let f = obj.get_or_insert("n", DataField::from_digit("n", 9));
+        assert_eq!(f.get_value(), &Value::Digit(5));
+        assert_eq!(obj.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_insert_with_is_lazy() {
+        let mut obj = ObjectValue::new();
+        obj.insert("a", DataField::from_chars("a", "x"));
+        let f = obj.get_or_insert_with("a", || panic!("default must not be built"));
+        assert_eq!(f.get_chars(), Some("x"));
+
+        let f = obj.get_or_insert_with("b", || DataField::from_bool("b", true));
+        assert_eq!(f.get_value(), &Value::Bool(true));
+    }
+
+    #[test]
+    fn test_get_or_default() {
+        let mut obj = ObjectValue::new();
+        let f = obj.get_or_default("count", DataType::Digit, Value::Digit(0));
+        assert_eq!(f.get_name(), "count");
+        assert_eq!(f.get_meta(), &DataType::Digit);
+        assert_eq!(f.get_value(), &Value::Digit(0));
+    }
+
+    // ========== JSON conversion tests ==========
+
+    #[test]
+    fn test_object_value_json_roundtrip() {
+        let json = serde_json::json!({"a": 1, "b": "hello", "c": true});
+        let obj = ObjectValue::try_from(json.clone()).unwrap();
+        assert_eq!(obj.len(), 3);
+        assert_eq!(obj.get("a").unwrap().get_meta(), &DataType::Digit);
+        assert_eq!(obj.get("b").unwrap().get_meta(), &DataType::Chars);
+        assert_eq!(obj.get("c").unwrap().get_meta(), &DataType::Bool);
+        assert_eq!(serde_json::Value::from(&obj), json);
+    }
+
+    #[test]
+    fn test_object_value_json_nested_and_errors() {
+        let json = serde_json::json!({"n": {"x": 1.5, "ip": "10.0.0.1"}, "l": [1, 2], "z": null});
+        let obj = ObjectValue::try_from(json.clone()).unwrap();
+        assert_eq!(serde_json::Value::from(&obj), json);
+
+        assert!(ObjectValue::try_from(serde_json::json!([1])).is_err());
+        assert!(ObjectValue::try_from(serde_json::json!({"big": u64::MAX})).is_err());
+    }
+
+    // ========== set_path_create tests ==========
+
+    #[test]
+    fn test_set_path_create_builds_nested_structure() {
+        let mut obj = ObjectValue::new();
+        assert!(obj.set_path_create("a.b.c", DataField::from_digit("c", 1)));
+
+        let b = nested(nested(&obj, "a"), "b");
+        assert_eq!(b.get("c").unwrap().get_value(), &Value::Digit(1));
+    }
+
+    #[test]
+    fn test_set_path_create_updates_existing_leaf() {
+        let mut obj = ObjectValue::new();
+        assert!(obj.set_path_create("a.b.c", DataField::from_digit("c", 1)));
+        assert!(!obj.set_path_create("a.b.c", DataField::from_digit("c", 2)));
+
+        let b = nested(nested(&obj, "a"), "b");
+        assert_eq!(b.len(), 1);
+        assert_eq!(b.get("c").unwrap().get_value(), &Value::Digit(2));
+    }
+
+    #[test]
+    fn test_set_path_create_new_leaf_under_existing_parent() {
+        let mut obj = ObjectValue::new();
+        obj.set_path_create("a.x", DataField::from_digit("x", 1));
+        assert!(obj.set_path_create("a.y", DataField::from_digit("y", 2)));
+        assert_eq!(nested(&obj, "a").len(), 2);
+    }
+
+    #[test]
+    fn test_set_path_create_conflict_with_non_object() {
+        let mut obj = ObjectValue::new();
+        obj.insert("a", DataField::from_chars("a", "scalar"));
+        assert!(!obj.set_path_create("a.b", DataField::from_digit("b", 1)));
+        assert_eq!(obj.get("a").unwrap().get_chars(), Some("scalar"));
+    }
+
+    #[test]
+    fn test_set_path_create_rejects_empty_segments() {
+        let mut obj = ObjectValue::new();
+        assert!(!obj.set_path_create("", DataField::from_digit("x", 1)));
+        assert!(!obj.set_path_create("a..b", DataField::from_digit("x", 1)));
+        assert!(obj.is_empty());
+    }
+
+    // ========== nested path tests ==========
+
+    #[test]
+    fn test_get_nested_three_levels() {
+        let mut obj = ObjectValue::new();
+        obj.insert_nested("user.address.city", DataField::from_chars("city", "hz"))
+            .unwrap();
+        obj.insert_nested("user.name", DataField::from_chars("name", "alice"))
+            .unwrap();
+
+        assert_eq!(
+            obj.get_nested("user.address.city"),
+            Some(&Value::from("hz"))
+        );
+        assert_eq!(obj.get_nested("user.name"), Some(&Value::from("alice")));
+        assert!(matches!(obj.get_nested("user"), Some(Value::Obj(_))));
+        assert_eq!(obj.get_nested("user.address.zip"), None);
+        assert_eq!(obj.get_nested("user.name.first"), None);
+        assert_eq!(obj.get_nested("nope.x"), None);
+    }
+
+    #[test]
+    fn test_insert_nested_errors() {
+        let mut obj = ObjectValue::new();
+        obj.insert("a", DataField::from_chars("a", "scalar"));
+        assert!(matches!(
+            obj.insert_nested("a.b", DataField::from_digit("b", 1)),
+            Err(ModelError::Validation(_))
+        ));
+        assert!(matches!(
+            obj.insert_nested("a..b", DataField::from_digit("b", 1)),
+            Err(ModelError::Parse(_))
+        ));
+        assert_eq!(obj.len(), 1);
+    }
+
+    // ========== deep_merge tests ==========
+
+    #[test]
+    fn test_deep_merge_resolves_conflicts() {
+        let mut base = ObjectValue::new();
+        base.insert_nested("db.conn.host", DataField::from_chars("host", "localhost"))
+            .unwrap();
+        base.insert_nested("db.conn.port", DataField::from_digit("port", 5432))
+            .unwrap();
+        base.insert_nested("db.name", DataField::from_chars("name", "app"))
+            .unwrap();
+        base.insert("debug", DataField::from_bool("debug", false));
+
+        let mut overrides = ObjectValue::new();
+        overrides
+            .insert_nested("db.conn.host", DataField::from_chars("host", "db.internal"))
+            .unwrap();
+        overrides
+            .insert_nested("db.conn.tls", DataField::from_bool("tls", true))
+            .unwrap();
+        overrides.insert("debug", DataField::from_digit("debug", 1));
+        overrides
+            .insert_nested("db.pool", DataField::from_digit("pool", 8))
+            .unwrap();
+
+        base.deep_merge(overrides);
+        assert_eq!(
+            base.get_nested("db.conn.host"),
+            Some(&Value::from("db.internal"))
+        );
+        assert_eq!(base.get_nested("db.conn.port"), Some(&Value::Digit(5432)));
+        assert_eq!(base.get_nested("db.conn.tls"), Some(&Value::Bool(true)));
+        assert_eq!(base.get_nested("db.name"), Some(&Value::from("app")));
+        assert_eq!(base.get_nested("db.pool"), Some(&Value::Digit(8)));
+        assert_eq!(base.get_nested("debug"), Some(&Value::Digit(1)));
+        assert_eq!(base.get("debug").unwrap().get_meta(), &DataType::Digit);
+    }
+
+    #[test]
+    fn test_deep_merge_object_replaces_scalar() {
+        let mut base = ObjectValue::new();
+        base.insert("a", DataField::from_digit("a", 1));
+        let mut other = ObjectValue::new();
+        other
+            .insert_nested("a.b", DataField::from_digit("b", 2))
+            .unwrap();
+        base.deep_merge(other);
+        assert_eq!(base.get_nested("a.b"), Some(&Value::Digit(2)));
+    }
+}
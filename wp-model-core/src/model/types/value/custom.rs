@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 use std::fmt::{Display, Formatter};
 
@@ -15,4 +16,49 @@ impl Display for MobilePhoneT {
         write!(f, "{}", self.0)
     }
 }
-use serde::{Deserialize, Serialize};
+/// 序列号：原始文本 + 末尾数字解析出的序号（超出 u32 时为 `None`）
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct SerialNumberT(pub String, pub Option<u32>);
+impl Display for SerialNumberT {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// 解析 `SN-00123`、`DEV-2024-042` 这类以数字结尾的序列号；不以数字结尾时返回 `None`。
+pub fn parse_sn(s: &str) -> Option<SerialNumberT> {
+    let s = s.trim();
+    let prefix_len = s.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let digits = &s[prefix_len..];
+    if digits.is_empty() {
+        return None;
+    }
+    Some(SerialNumberT(s.to_string(), digits.parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sn() {
+        assert_eq!(
+            parse_sn("SN-00123"),
+            Some(SerialNumberT("SN-00123".into(), Some(123)))
+        );
+        assert_eq!(
+            parse_sn("DEV-2024-042"),
+            Some(SerialNumberT("DEV-2024-042".into(), Some(42)))
+        );
+        assert_eq!(parse_sn("ABCDEF"), None);
+        assert_eq!(parse_sn(""), None);
+        assert_eq!(parse_sn("SN-1A"), None);
+    }
+
+    #[test]
+    fn test_parse_sn_overflowing_sequence() {
+        let sn = parse_sn("SN-99999999999").unwrap();
+        assert_eq!(sn.0, "SN-99999999999");
+        assert_eq!(sn.1, None);
+    }
+}
@@ -3,19 +3,22 @@ mod custom;
 mod network;
 mod primitive;
 use crate::model::DataField;
+use crate::model::DataType;
 use crate::model::FValueStr;
 use crate::model::data::field::Field;
+use crate::model::error::{ModelError, ValueConvertError};
 use crate::traits::AsValueRef;
 use smol_str::SmolStr;
 use std::fmt::{Debug, Display, Formatter};
 use std::net::IpAddr;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 pub use composite::{IgnoreT, ObjectValue};
-pub use custom::{IdCardT, MobilePhoneT};
-pub use network::{DomainT, EmailT, IpNetValue, UrlValue};
-pub use primitive::{DateTimeValue, DigitValue, FloatValue, HexT};
+pub use custom::{IdCardT, MobilePhoneT, SerialNumberT, parse_sn};
+pub use network::{DomainT, EmailT, IpNetValue, MacAddrValue, UrlValue};
+pub use primitive::{DateTimeValue, DigitValue, FloatValue, HexT, UuidValue};
 use serde::{Deserialize, Serialize};
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct SymbolValue(pub SmolStr);
@@ -38,6 +41,7 @@ pub enum Value {
     Email(EmailT),
     IdCard(IdCardT),
     MobilePhone(MobilePhoneT),
+    SN(SerialNumberT),
     Hex(HexT),
     // 复合类型
     //Obj(BTreeMap<String, Field<Value>>),
@@ -45,6 +49,13 @@ pub enum Value {
     Array(Vec<Field<Value>>),
     Symbol(SmolStr),
     Ignore(IgnoreT),
+    // 新增变体只追加在末尾，保持已有变体的 serde 序号不变
+    /// 时间跨度，序列化为纳秒数（`u64`）
+    Duration(#[serde(with = "primitive::duration_nanos_serde")] Duration),
+    /// 序列化为 `AA:BB:CC:DD:EE:FF` 形式的字符串
+    MacAddr(#[serde(with = "network::mac_addr_serde")] [u8; 6]),
+    /// 序列化为小写连字符格式的字符串；与 `uuid::Uuid` 的转换需启用 `uuid-support` feature
+    Uuid(#[serde(with = "primitive::uuid_serde")] [u8; 16]),
 }
 
 impl AsValueRef<Value> for Value {
@@ -172,11 +183,32 @@ impl From<IpAddr> for Value {
         Self::IpAddr(value)
     }
 }
+impl From<[u8; 6]> for Value {
+    fn from(value: [u8; 6]) -> Self {
+        Self::MacAddr(value)
+    }
+}
 impl From<HexT> for Value {
     fn from(value: HexT) -> Self {
         Self::Hex(value)
     }
 }
+impl From<[u8; 16]> for Value {
+    fn from(value: [u8; 16]) -> Self {
+        Self::Uuid(value)
+    }
+}
+#[cfg(feature = "uuid-support")]
+impl From<uuid::Uuid> for Value {
+    fn from(value: uuid::Uuid) -> Self {
+        Self::Uuid(value.into_bytes())
+    }
+}
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        Self::Duration(value)
+    }
+}
 impl From<DateTimeValue> for Value {
     fn from(value: DateTimeValue) -> Self {
         Self::Time(value)
@@ -230,18 +262,170 @@ impl From<MobilePhoneT> for Value {
     }
 }
 
+impl From<SerialNumberT> for Value {
+    fn from(value: SerialNumberT) -> Self {
+        Self::SN(value)
+    }
+}
+
+fn convert_err(expected: &'static str, actual: &Value) -> ValueConvertError {
+    ValueConvertError {
+        expected,
+        actual: actual.tag().to_string(),
+    }
+}
+
+/// 仅接受 `Digit`
+impl TryFrom<Value> for i64 {
+    type Error = ValueConvertError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Digit(v) => Ok(v),
+            other => Err(convert_err("i64", &other)),
+        }
+    }
+}
+
+/// 接受 `Float` 与 `Digit`（按 `as f64` 转换）
+impl TryFrom<Value> for f64 {
+    type Error = ValueConvertError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Float(v) => Ok(v),
+            Value::Digit(v) => Ok(v as f64),
+            other => Err(convert_err("f64", &other)),
+        }
+    }
+}
+
+impl TryFrom<Value> for bool {
+    type Error = ValueConvertError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bool(v) => Ok(v),
+            other => Err(convert_err("bool", &other)),
+        }
+    }
+}
+
+/// 接受字符串类变体（同 [`Value::as_str_value`]），取其文本
+impl TryFrom<Value> for String {
+    type Error = ValueConvertError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value.as_str_value() {
+            Some(s) => Ok(s.to_string()),
+            None => Err(convert_err("String", &value)),
+        }
+    }
+}
+
+impl TryFrom<Value> for IpAddr {
+    type Error = ValueConvertError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::IpAddr(v) => Ok(v),
+            other => Err(convert_err("IpAddr", &other)),
+        }
+    }
+}
+
+/// JSON 到 `Value` 的转换：整数为 Digit，小数为 Float，可解析为 IP 的字符串为 IpAddr，
+/// 对象/数组递归转换（数组元素字段名为 `item`）。超出 i64 的无符号整数返回错误。
+impl TryFrom<serde_json::Value> for Value {
+    type Error = ModelError;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        Ok(match value {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(b) => Value::Bool(b),
+            serde_json::Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    Value::Digit(i)
+                } else if n.is_u64() {
+                    return Err(ModelError::Parse(format!("number out of i64 range: {n}")));
+                } else {
+                    Value::Float(n.as_f64().unwrap_or_default())
+                }
+            }
+            serde_json::Value::String(s) => match s.parse::<IpAddr>() {
+                Ok(ip) => Value::IpAddr(ip),
+                Err(_) => Value::Chars(s.into()),
+            },
+            serde_json::Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|v| json_field("item", v))
+                    .collect::<Result<_, _>>()?,
+            ),
+            serde_json::Value::Object(map) => {
+                let mut obj = ObjectValue::new();
+                for (k, v) in map {
+                    let field = json_field(k.as_str(), v)?;
+                    obj.insert(k, field);
+                }
+                Value::Obj(obj)
+            }
+        })
+    }
+}
+
+/// 将 JSON 值转换为字段，类型由 [`DataType::infer_from_value`] 推断。
+pub(crate) fn json_field(name: &str, value: serde_json::Value) -> Result<DataField, ModelError> {
+    let value = Value::try_from(value)?;
+    Ok(Field::new(DataType::infer_from_value(&value), name, value))
+}
+
+/// `Value` 到 JSON 的转换，规则同 `From<&ObjectValue> for serde_json::Value`。
+pub(crate) fn value_to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Null | Value::Ignore(_) => serde_json::Value::Null,
+        Value::Bool(b) => serde_json::Value::Bool(*b),
+        Value::Digit(v) => serde_json::Value::from(*v),
+        Value::Float(v) => serde_json::Number::from_f64(*v)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Value::Obj(obj) => obj.into(),
+        Value::Array(items) => items.iter().map(|f| value_to_json(f.get_value())).collect(),
+        other => match other.as_str_value() {
+            Some(s) => serde_json::Value::from(s),
+            None => serde_json::Value::String(other.to_string()),
+        },
+    }
+}
+
+/// 规则同 [`value_to_json`]：Null/Ignore → `null`，Digit/Float/Bool 为原生类型，
+/// Obj/Array 递归转换，其余变体输出为字符串（非有限浮点数输出 `null`）。
+impl From<&Value> for serde_json::Value {
+    fn from(value: &Value) -> Self {
+        value_to_json(value)
+    }
+}
+
+impl From<Value> for serde_json::Value {
+    fn from(value: Value) -> Self {
+        value_to_json(&value)
+    }
+}
+
 impl Display for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Value::Time(time) => {
                 write!(f, "{}", time)
             }
+            Value::Duration(d) => write!(f, "{}", primitive::iso8601_duration(d)),
             Value::IpNet(ip) => {
                 write!(f, "{}", ip)
             }
             Value::IpAddr(addr) => {
                 write!(f, "{}", addr)
             }
+            Value::MacAddr(mac) => write!(f, "{}", MacAddrValue::format(mac)),
             Value::Float(float) => {
                 write!(f, "{}", float)
             }
@@ -257,6 +441,7 @@ impl Display for Value {
             Value::Hex(hex) => {
                 write!(f, "{}", hex)
             }
+            Value::Uuid(id) => write!(f, "{}", UuidValue::format(id)),
             Value::Obj(obj) => {
                 write!(f, "{:?}", obj)
             }
@@ -272,6 +457,7 @@ impl Display for Value {
             Value::Email(v) => write!(f, "{}", v.0),
             Value::IdCard(v) => write!(f, "{}", v.0),
             Value::MobilePhone(v) => write!(f, "{}", v.0),
+            Value::SN(v) => write!(f, "{}", v.0),
             Value::Ignore(_) => {
                 write!(f, "")
             }
@@ -312,6 +498,372 @@ impl Value {
     fn ensure_chars_unique(&mut self) {
         // No-op: SmolStr handles uniqueness internally
     }
+
+    pub fn is_symbol(&self) -> bool {
+        matches!(self, Value::Symbol(_))
+    }
+
+    pub fn is_chars(&self) -> bool {
+        matches!(self, Value::Chars(_))
+    }
+
+    /// 读取字符串语义值的内部文本：Chars、Symbol、Domain、Url、Email、IdCard、MobilePhone、SN。
+    ///
+    /// `PeekSymbol` 仅是 `DataType`，其值同样以 `Symbol` 存储。
+    pub fn as_str_value(&self) -> Option<&str> {
+        match self {
+            Value::Chars(s) => Some(s.as_str()),
+            Value::Symbol(s) => Some(s.as_str()),
+            Value::Domain(v) => Some(v.0.as_str()),
+            Value::Url(v) => Some(v.0.as_str()),
+            Value::Email(v) => Some(v.0.as_str()),
+            Value::IdCard(v) => Some(v.0.as_str()),
+            Value::MobilePhone(v) => Some(v.0.as_str()),
+            Value::SN(v) => Some(v.0.as_str()),
+            _ => None,
+        }
+    }
+
+    /// 字符串类变体（同 [`Value::as_str_value`]）的 UTF-8 字节长度，其他类型返回 `None`。
+    pub fn byte_len(&self) -> Option<usize> {
+        self.as_str_value().map(str::len)
+    }
+
+    /// 字符串类变体的字符（Unicode 标量值）数量，其他类型返回 `None`。
+    pub fn char_len(&self) -> Option<usize> {
+        self.as_str_value().map(|s| s.chars().count())
+    }
+
+    /// 类型化读取：变体不匹配时返回 `None`。`try_as_chars` 仅匹配 `Chars`（同 [`Value::as_str`]）。
+    pub fn try_as_digit(&self) -> Option<i64> {
+        match self {
+            Value::Digit(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_float(&self) -> Option<f64> {
+        match self {
+            Value::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_chars(&self) -> Option<&str> {
+        self.as_str()
+    }
+
+    pub fn try_as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_ip(&self) -> Option<IpAddr> {
+        match self {
+            Value::IpAddr(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_ip_net(&self) -> Option<&IpNetValue> {
+        match self {
+            Value::IpNet(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_obj(&self) -> Option<&ObjectValue> {
+        match self {
+            Value::Obj(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn try_as_array(&self) -> Option<&[DataField]> {
+        match self {
+            Value::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 拥有型读取，取出内部值而不克隆；变体不匹配时返回 `None`。
+    pub fn try_into_digit(self) -> Option<i64> {
+        self.try_as_digit()
+    }
+
+    pub fn try_into_float(self) -> Option<f64> {
+        self.try_as_float()
+    }
+
+    pub fn try_into_chars(self) -> Option<FValueStr> {
+        match self {
+            Value::Chars(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_bool(self) -> Option<bool> {
+        self.try_as_bool()
+    }
+
+    pub fn try_into_ip(self) -> Option<IpAddr> {
+        self.try_as_ip()
+    }
+
+    pub fn try_into_ip_net(self) -> Option<IpNetValue> {
+        match self {
+            Value::IpNet(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_obj(self) -> Option<ObjectValue> {
+        match self {
+            Value::Obj(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn try_into_array(self) -> Option<Vec<DataField>> {
+        match self {
+            Value::Array(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// 数值加法：Digit 溢出或非数值类型返回 `None`，Digit 与 Float 混合时提升为 Float；
+    /// 两个 `Chars` 相加为字符串拼接。
+    pub fn add(&self, other: &Value) -> Option<Value> {
+        if let (Value::Chars(a), Value::Chars(b)) = (self, other) {
+            return Some(Value::Chars(format!("{a}{b}").into()));
+        }
+        self.arith(other, i64::checked_add, |a, b| Some(a + b))
+    }
+
+    /// 数值减法，规则同 [`Value::add`]（`Chars` 不支持，返回 `None`）。
+    pub fn sub(&self, other: &Value) -> Option<Value> {
+        self.arith(other, i64::checked_sub, |a, b| Some(a - b))
+    }
+
+    /// 数值乘法，规则同 [`Value::add`]（`Chars` 不支持，返回 `None`）。
+    pub fn mul(&self, other: &Value) -> Option<Value> {
+        self.arith(other, i64::checked_mul, |a, b| Some(a * b))
+    }
+
+    /// 数值除法，规则同 [`Value::add`]；除数为 0 时返回 `None`。
+    pub fn div(&self, other: &Value) -> Option<Value> {
+        self.arith(other, i64::checked_div, |a, b| (b != 0.0).then(|| a / b))
+    }
+
+    /// 毫秒数转为 `Value::Duration`；负数、NaN 或超出范围时返回 `Value::Null`
+    pub fn from_millis_f64(ms: f64) -> Value {
+        Self::from_secs_f64(ms / 1000.0)
+    }
+
+    /// 秒数转为 `Value::Duration`；负数、NaN 或超出范围时返回 `Value::Null`
+    pub fn from_secs_f64(s: f64) -> Value {
+        Duration::try_from_secs_f64(s).map_or(Value::Null, Value::Duration)
+    }
+
+    /// 将文本形态的 `Chars` 规范化为具体类型：`true`/`false` → `Bool`，整数 → `Digit`，
+    /// 有限浮点数 → `Float`；无法识别的文本及其他类型原样返回。
+    pub fn normalize(self) -> Value {
+        let Value::Chars(s) = &self else {
+            return self;
+        };
+        let text = s.trim();
+        if text.eq_ignore_ascii_case("true") {
+            Value::Bool(true)
+        } else if text.eq_ignore_ascii_case("false") {
+            Value::Bool(false)
+        } else if let Ok(v) = text.parse::<i64>() {
+            Value::Digit(v)
+        } else if let Some(v) = text.parse::<f64>().ok().filter(|v| v.is_finite()) {
+            Value::Float(v)
+        } else {
+            self
+        }
+    }
+
+    /// 向 `Array` 追加元素，同 [`array_push`]。
+    pub fn push_to_array(&mut self, field: DataField) -> bool {
+        array_push(self, field)
+    }
+
+    /// 跨变体的全序：先按类别 Null < Bool < 数值 < Time < Duration < IpAddr < 字符串类 < 其他 排序，
+    /// 同类内按值比较。Digit/Float 统一按 `f64::total_cmp` 比较（相等时 Digit 在前），
+    /// 字符串类（同 [`Value::as_str_value`]）按文本，其他按 `tag` 与 `Display` 文本。
+    pub fn total_cmp(&self, other: &Value) -> std::cmp::Ordering {
+        fn rank(v: &Value) -> u8 {
+            match v {
+                Value::Null => 0,
+                Value::Bool(_) => 1,
+                Value::Digit(_) | Value::Float(_) => 2,
+                Value::Time(_) => 3,
+                Value::Duration(_) => 4,
+                Value::IpAddr(_) => 5,
+                _ if v.as_str_value().is_some() => 6,
+                _ => 7,
+            }
+        }
+        fn as_f64(v: &Value) -> f64 {
+            match v {
+                Value::Digit(d) => *d as f64,
+                Value::Float(f) => *f,
+                _ => 0.0,
+            }
+        }
+        rank(self)
+            .cmp(&rank(other))
+            .then_with(|| match (self, other) {
+                (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+                (Value::Digit(a), Value::Digit(b)) => a.cmp(b),
+                (Value::Time(a), Value::Time(b)) => a.cmp(b),
+                (Value::Duration(a), Value::Duration(b)) => a.cmp(b),
+                (Value::IpAddr(a), Value::IpAddr(b)) => a.cmp(b),
+                (Value::Digit(_) | Value::Float(_), _) => as_f64(self)
+                    .total_cmp(&as_f64(other))
+                    .then_with(|| self.tag().cmp(other.tag())),
+                _ => match (self.as_str_value(), other.as_str_value()) {
+                    (Some(a), Some(b)) => a.cmp(b).then_with(|| self.tag().cmp(other.tag())),
+                    _ => self
+                        .tag()
+                        .cmp(other.tag())
+                        .then_with(|| self.to_string().cmp(&other.to_string())),
+                },
+            })
+    }
+
+    /// 可按字节比较的排序键：首字节为类型标识，Null < Bool < Digit < Float < Time < Duration
+    /// < IpAddr < 字符串类（同 [`Value::as_str_value`]）< 其他。
+    ///
+    /// Digit 为翻转符号位的大端序；Float 负数翻转全部位、非负数仅翻转符号位；
+    /// Time 为秒数（同 Digit 编码）加纳秒大端序；Duration 为秒数与纳秒的大端序；IpAddr 先 v4 后 v6；字符串类为原始 UTF-8 字节；
+    /// 其他类型为 `tag`、`0x00` 与 `Display` 文本。与 [`Value::total_cmp`] 不同，Digit 与 Float 不混合比较。
+    pub fn to_sortable_bytes(&self) -> Vec<u8> {
+        fn signed(v: i64) -> [u8; 8] {
+            ((v as u64) ^ (1 << 63)).to_be_bytes()
+        }
+        let mut out = Vec::with_capacity(17);
+        match self {
+            Value::Null => out.push(0),
+            Value::Bool(b) => out.extend([1, *b as u8]),
+            Value::Digit(d) => {
+                out.push(2);
+                out.extend(signed(*d));
+            }
+            Value::Float(f) => {
+                let bits = f.to_bits();
+                let key = if bits >> 63 == 1 {
+                    !bits
+                } else {
+                    bits ^ (1 << 63)
+                };
+                out.push(3);
+                out.extend(key.to_be_bytes());
+            }
+            Value::Time(t) => {
+                let t = t.and_utc();
+                out.push(4);
+                out.extend(signed(t.timestamp()));
+                out.extend(t.timestamp_subsec_nanos().to_be_bytes());
+            }
+            Value::Duration(d) => {
+                out.push(5);
+                out.extend(d.as_secs().to_be_bytes());
+                out.extend(d.subsec_nanos().to_be_bytes());
+            }
+            Value::IpAddr(IpAddr::V4(ip)) => {
+                out.extend([6, 4]);
+                out.extend(ip.octets());
+            }
+            Value::IpAddr(IpAddr::V6(ip)) => {
+                out.extend([6, 6]);
+                out.extend(ip.octets());
+            }
+            _ => match self.as_str_value() {
+                Some(text) => {
+                    out.push(7);
+                    out.extend(text.as_bytes());
+                }
+                None => {
+                    out.push(8);
+                    out.extend(self.tag().as_bytes());
+                    out.push(0);
+                    out.extend(self.to_string().as_bytes());
+                }
+            },
+        }
+        out
+    }
+
+    fn arith(
+        &self,
+        other: &Value,
+        int_op: fn(i64, i64) -> Option<i64>,
+        float_op: fn(f64, f64) -> Option<f64>,
+    ) -> Option<Value> {
+        match (self, other) {
+            (Value::Digit(a), Value::Digit(b)) => int_op(*a, *b).map(Value::Digit),
+            (Value::Float(a), Value::Float(b)) => float_op(*a, *b).map(Value::Float),
+            (Value::Digit(a), Value::Float(b)) => float_op(*a as f64, *b).map(Value::Float),
+            (Value::Float(a), Value::Digit(b)) => float_op(*a, *b as f64).map(Value::Float),
+            _ => None,
+        }
+    }
+}
+
+/// `Chars` 转为 `Symbol`，其他类型返回 `None`。
+pub fn chars_to_symbol(v: &Value) -> Option<Value> {
+    match v {
+        Value::Chars(s) => Some(Value::Symbol(s.clone())),
+        _ => None,
+    }
+}
+
+/// `Symbol` 转为 `Chars`，其他类型返回 `None`。
+pub fn symbol_to_chars(v: &Value) -> Option<Value> {
+    match v {
+        Value::Symbol(s) => Some(Value::Chars(s.clone())),
+        _ => None,
+    }
+}
+
+/// 向 `Array` 末尾追加元素；`v` 不是 `Array` 时返回 `false` 且不做修改。
+pub fn array_push(v: &mut Value, field: DataField) -> bool {
+    match v {
+        Value::Array(items) => {
+            items.push(field);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// 移除并返回 `Array` 中 `index` 处的元素；非 `Array` 或越界时返回 `None`。
+pub fn array_remove_at(v: &mut Value, index: usize) -> Option<DataField> {
+    match v {
+        Value::Array(items) if index < items.len() => Some(items.remove(index)),
+        _ => None,
+    }
+}
+
+/// `Array` 元素个数，非 `Array` 返回 `None`。
+pub fn array_len(v: &Value) -> Option<usize> {
+    match v {
+        Value::Array(items) => Some(items.len()),
+        _ => None,
+    }
+}
+
+/// `Array` 中 `index` 处的元素；非 `Array` 或越界时返回 `None`。
+pub fn array_get(v: &Value, index: usize) -> Option<&DataField> {
+    match v {
+        Value::Array(items) => items.get(index),
+        _ => None,
+    }
 }
 
 // Comparison impls moved to orion_exp adapters to decouple core from orion_exp.
@@ -463,6 +1015,615 @@ mod tests {
         assert_eq!(v, Value::Obj(obj));
     }
 
+    // ========== typed extraction tests ==========
+
+    #[test]
+    fn test_value_try_as_matching_variants() {
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let net = IpNetValue::new(ip, 8).unwrap();
+        let mut obj = ObjectValue::default();
+        obj.insert("k", DataField::from_digit("k", 1));
+        let arr = vec![DataField::from_digit("a", 1)];
+
+        assert_eq!(Value::Digit(7).try_as_digit(), Some(7));
+        assert_eq!(Value::Float(1.5).try_as_float(), Some(1.5));
+        assert_eq!(Value::from("abc").try_as_chars(), Some("abc"));
+        assert_eq!(Value::Bool(true).try_as_bool(), Some(true));
+        assert_eq!(Value::IpAddr(ip).try_as_ip(), Some(ip));
+        assert_eq!(Value::IpNet(net.clone()).try_as_ip_net(), Some(&net));
+        assert_eq!(Value::Obj(obj.clone()).try_as_obj(), Some(&obj));
+        assert_eq!(Value::Array(arr.clone()).try_as_array(), Some(&arr[..]));
+    }
+
+    #[test]
+    fn test_value_try_as_mismatch_returns_none() {
+        let v = Value::from("7");
+        assert_eq!(v.try_as_digit(), None);
+        assert_eq!(v.try_as_float(), None);
+        assert_eq!(v.try_as_bool(), None);
+        assert_eq!(v.try_as_ip(), None);
+        assert!(v.try_as_ip_net().is_none());
+        assert!(v.try_as_obj().is_none());
+        assert!(v.try_as_array().is_none());
+        assert_eq!(Value::Digit(7).try_as_chars(), None);
+        assert_eq!(Value::Symbol("s".into()).try_as_chars(), None);
+        assert_eq!(Value::Digit(1).try_as_float(), None);
+    }
+
+    #[test]
+    fn test_value_try_into_owned() {
+        let ip: IpAddr = "::1".parse().unwrap();
+        let net = IpNetValue::new(ip, 64).unwrap();
+        let arr = vec![DataField::from_digit("a", 1)];
+
+        assert_eq!(Value::Digit(7).try_into_digit(), Some(7));
+        assert_eq!(Value::Float(2.0).try_into_float(), Some(2.0));
+        assert_eq!(Value::from("abc").try_into_chars(), Some("abc".into()));
+        assert_eq!(Value::Bool(false).try_into_bool(), Some(false));
+        assert_eq!(Value::IpAddr(ip).try_into_ip(), Some(ip));
+        assert_eq!(Value::IpNet(net.clone()).try_into_ip_net(), Some(net));
+        assert_eq!(
+            Value::Obj(ObjectValue::default()).try_into_obj(),
+            Some(ObjectValue::default())
+        );
+        assert_eq!(Value::Array(arr.clone()).try_into_array(), Some(arr));
+
+        assert_eq!(Value::Null.try_into_digit(), None);
+        assert_eq!(Value::Null.try_into_float(), None);
+        assert_eq!(Value::Null.try_into_chars(), None);
+        assert_eq!(Value::Null.try_into_bool(), None);
+        assert_eq!(Value::Null.try_into_ip(), None);
+        assert_eq!(Value::Null.try_into_ip_net(), None);
+        assert_eq!(Value::Null.try_into_obj(), None);
+        assert_eq!(Value::Null.try_into_array(), None);
+    }
+
+    // ========== TryFrom<Value> tests ==========
+
+    #[test]
+    fn test_try_from_value_for_i64_and_f64() {
+        assert_eq!(i64::try_from(Value::Digit(42)), Ok(42));
+        let err = i64::try_from(Value::Float(1.5)).unwrap_err();
+        assert_eq!(err.expected, "i64");
+        assert_eq!(err.actual, "Float");
+        assert_eq!(err.to_string(), "cannot convert Float value to i64");
+
+        assert_eq!(f64::try_from(Value::Float(1.5)), Ok(1.5));
+        assert_eq!(f64::try_from(Value::Digit(2)), Ok(2.0));
+        assert!(f64::try_from(Value::from("1.5")).is_err());
+    }
+
+    #[test]
+    fn test_try_from_value_for_bool_string_ip() {
+        assert_eq!(bool::try_from(Value::Bool(true)), Ok(true));
+        assert_eq!(bool::try_from(Value::Digit(1)).unwrap_err().actual, "Digit");
+
+        assert_eq!(String::try_from(Value::from("abc")), Ok("abc".to_string()));
+        assert_eq!(
+            String::try_from(Value::Symbol("sym".into())),
+            Ok("sym".to_string())
+        );
+        assert_eq!(
+            String::try_from(Value::Email(EmailT("a@b.c".into()))),
+            Ok("a@b.c".to_string())
+        );
+        assert!(String::try_from(Value::Null).is_err());
+
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        assert_eq!(IpAddr::try_from(Value::IpAddr(ip)), Ok(ip));
+        let err = IpAddr::try_from(Value::from("10.0.0.1")).unwrap_err();
+        assert_eq!(err.actual, "Chars");
+    }
+
+    #[test]
+    fn test_try_from_value_question_mark() {
+        fn port(v: Value) -> Result<i64, ModelError> {
+            Ok(i64::try_from(v)?)
+        }
+        assert_eq!(port(Value::Digit(80)).unwrap(), 80);
+        assert!(matches!(port(Value::Null), Err(ModelError::Validation(_))));
+    }
+
+    // ========== length tests ==========
+
+    #[test]
+    fn test_value_byte_len_and_char_len() {
+        let v = Value::Chars("héllo".into());
+        assert_eq!(v.byte_len(), Some(6));
+        assert_eq!(v.char_len(), Some(5));
+
+        let sym = Value::Symbol("中文".into());
+        assert_eq!(sym.byte_len(), Some(6));
+        assert_eq!(sym.char_len(), Some(2));
+
+        assert_eq!(Value::Digit(12345).byte_len(), None);
+        assert_eq!(Value::Float(1.5).char_len(), None);
+        assert_eq!(Value::Bool(true).byte_len(), None);
+        assert_eq!(Value::Null.char_len(), None);
+    }
+
+    // ========== Symbol / Chars interop tests ==========
+
+    #[test]
+    fn test_chars_symbol_roundtrip() {
+        let chars = Value::Chars("foo".into());
+        let sym = chars_to_symbol(&chars).unwrap();
+        assert!(sym.is_symbol());
+        assert!(!sym.is_chars());
+        assert_eq!(symbol_to_chars(&sym), Some(chars.clone()));
+
+        assert_eq!(chars_to_symbol(&sym), None);
+        assert_eq!(symbol_to_chars(&chars), None);
+        assert_eq!(chars_to_symbol(&Value::Digit(1)), None);
+    }
+
+    #[test]
+    fn test_as_str_value() {
+        assert_eq!(Value::Chars("a".into()).as_str_value(), Some("a"));
+        assert_eq!(Value::Symbol("GET".into()).as_str_value(), Some("GET"));
+        assert_eq!(
+            Value::Domain(DomainT("example.com".into())).as_str_value(),
+            Some("example.com")
+        );
+        assert_eq!(
+            Value::Email(EmailT("a@b.c".into())).as_str_value(),
+            Some("a@b.c")
+        );
+        assert_eq!(
+            Value::MobilePhone(MobilePhoneT("13800138000".into())).as_str_value(),
+            Some("13800138000")
+        );
+        assert_eq!(Value::Digit(1).as_str_value(), None);
+        // as_str 仍只接受 Chars
+        assert_eq!(Value::Symbol("GET".into()).as_str(), None);
+    }
+
+    // ========== JSON conversion tests ==========
+
+    #[test]
+    fn test_try_from_json_scalars() {
+        use serde_json::json;
+        assert_eq!(Value::try_from(json!(null)).unwrap(), Value::Null);
+        assert_eq!(Value::try_from(json!(true)).unwrap(), Value::Bool(true));
+        assert_eq!(Value::try_from(json!(42)).unwrap(), Value::Digit(42));
+        assert_eq!(Value::try_from(json!(1.5)).unwrap(), Value::Float(1.5));
+        assert_eq!(
+            Value::try_from(json!("10.0.0.1")).unwrap(),
+            Value::IpAddr(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+        );
+        assert_eq!(
+            Value::try_from(json!("hello")).unwrap(),
+            Value::Chars("hello".into())
+        );
+        assert!(Value::try_from(json!(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_try_from_json_nested() {
+        let v = Value::try_from(serde_json::json!({"a": {"b": 1}, "list": [1, 2]})).unwrap();
+        let Value::Obj(obj) = v else {
+            panic!("expected object");
+        };
+        let inner = obj.get("a").unwrap();
+        assert_eq!(inner.get_meta(), &DataType::Obj);
+        let list = obj.get("list").unwrap();
+        assert_eq!(list.get_meta(), &DataType::Array("digit".into()));
+    }
+
+    #[test]
+    fn test_value_into_json_and_round_trip() {
+        use serde_json::json;
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let primitives = vec![
+            Value::Null,
+            Value::Bool(false),
+            Value::Digit(-7),
+            Value::Float(2.5),
+            Value::from("hello"),
+            Value::IpAddr(ip),
+        ];
+        for v in primitives {
+            let j = serde_json::Value::from(&v);
+            assert_eq!(Value::try_from(j).unwrap(), v);
+        }
+
+        assert_eq!(
+            serde_json::Value::from(Value::Domain(DomainT("a.com".into()))),
+            json!("a.com")
+        );
+        assert_eq!(serde_json::Value::from(Value::Float(f64::NAN)), json!(null));
+        let arr = Value::Array(vec![DataField::from_digit("item", 1)]);
+        assert_eq!(serde_json::Value::from(arr), json!([1]));
+        let obj = Value::try_from(json!({"a": {"b": true}})).unwrap();
+        assert_eq!(serde_json::Value::from(obj), json!({"a": {"b": true}}));
+    }
+
+    // ========== normalize tests ==========
+
+    #[test]
+    fn test_value_normalize() {
+        assert_eq!(Value::from("42").normalize(), Value::Digit(42));
+        assert_eq!(Value::from(" -7 ").normalize(), Value::Digit(-7));
+        assert_eq!(Value::from("1.5").normalize(), Value::Float(1.5));
+        assert_eq!(Value::from("TRUE").normalize(), Value::Bool(true));
+        assert_eq!(Value::from("false").normalize(), Value::Bool(false));
+        assert_eq!(Value::from("alice").normalize(), Value::from("alice"));
+        assert_eq!(Value::from("NaN").normalize(), Value::from("NaN"));
+        assert_eq!(Value::from("").normalize(), Value::from(""));
+        assert_eq!(
+            Value::Symbol("1".into()).normalize(),
+            Value::Symbol("1".into())
+        );
+    }
+
+    // ========== arithmetic tests ==========
+
+    #[test]
+    fn test_value_add_sub_mul() {
+        assert_eq!(Value::Digit(3).add(&Value::Digit(4)), Some(Value::Digit(7)));
+        assert_eq!(
+            Value::Digit(3).sub(&Value::Digit(4)),
+            Some(Value::Digit(-1))
+        );
+        assert_eq!(
+            Value::Digit(3).mul(&Value::Digit(4)),
+            Some(Value::Digit(12))
+        );
+        assert_eq!(
+            Value::Float(1.5).add(&Value::Float(2.0)),
+            Some(Value::Float(3.5))
+        );
+        assert_eq!(
+            Value::Digit(2).mul(&Value::Float(1.5)),
+            Some(Value::Float(3.0))
+        );
+        assert_eq!(
+            Value::Float(2.5).sub(&Value::Digit(1)),
+            Some(Value::Float(1.5))
+        );
+    }
+
+    #[test]
+    fn test_value_arith_non_numeric() {
+        assert_eq!(Value::Digit(3).add(&Value::Chars("x".into())), None);
+        assert_eq!(Value::Bool(true).mul(&Value::Digit(1)), None);
+        assert_eq!(Value::Null.sub(&Value::Null), None);
+        assert_eq!(
+            Value::Chars("a".into()).sub(&Value::Chars("b".into())),
+            None
+        );
+        assert_eq!(
+            Value::Chars("a".into()).mul(&Value::Chars("b".into())),
+            None
+        );
+    }
+
+    #[test]
+    fn test_value_add_chars_concat() {
+        assert_eq!(
+            Value::Chars("foo".into()).add(&Value::Chars("bar".into())),
+            Some(Value::Chars("foobar".into()))
+        );
+        assert_eq!(Value::Chars("1".into()).add(&Value::Digit(1)), None);
+    }
+
+    #[test]
+    fn test_value_arith_overflow_and_div_zero() {
+        assert_eq!(Value::Digit(i64::MAX).add(&Value::Digit(1)), None);
+        assert_eq!(Value::Digit(i64::MIN).sub(&Value::Digit(1)), None);
+        assert_eq!(Value::Digit(10).div(&Value::Digit(0)), None);
+        assert_eq!(Value::Float(1.0).div(&Value::Float(0.0)), None);
+        assert_eq!(Value::Digit(1).div(&Value::Float(0.0)), None);
+        assert_eq!(Value::Digit(7).div(&Value::Digit(2)), Some(Value::Digit(3)));
+        assert_eq!(
+            Value::Float(7.0).div(&Value::Digit(2)),
+            Some(Value::Float(3.5))
+        );
+    }
+
+    // ========== total_cmp tests ==========
+
+    #[test]
+    fn test_value_total_cmp() {
+        use std::cmp::Ordering;
+        assert_eq!(Value::Digit(1).total_cmp(&Value::Digit(2)), Ordering::Less);
+        assert_eq!(
+            Value::Digit(2).total_cmp(&Value::Float(1.5)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Value::Digit(1).total_cmp(&Value::Float(1.0)),
+            Ordering::Less
+        );
+        assert_eq!(Value::Null.total_cmp(&Value::Bool(false)), Ordering::Less);
+        assert_eq!(
+            Value::from("b").total_cmp(&Value::from("a")),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Value::Digit(100).total_cmp(&Value::from("1")),
+            Ordering::Less
+        );
+        assert_eq!(Value::Null.total_cmp(&Value::Null), Ordering::Equal);
+
+        let mut values = vec![
+            Value::Float(2.5),
+            Value::Null,
+            Value::Digit(3),
+            Value::Digit(-1),
+        ];
+        values.sort_by(Value::total_cmp);
+        assert_eq!(
+            values,
+            vec![
+                Value::Null,
+                Value::Digit(-1),
+                Value::Float(2.5),
+                Value::Digit(3)
+            ]
+        );
+    }
+
+    // ========== Duration tests ==========
+
+    #[test]
+    fn test_value_duration_display_and_constructors() {
+        let v = Value::from(Duration::from_millis(1234));
+        assert_eq!(v.to_string(), "PT1.234S");
+        assert_eq!(v.tag(), "Duration");
+        assert_eq!(DataType::infer_from_value(&v), DataType::Duration);
+
+        assert_eq!(Value::from_millis_f64(1234.0), v);
+        assert_eq!(
+            Value::from_secs_f64(1.5),
+            Value::Duration(Duration::from_millis(1500))
+        );
+        assert_eq!(Value::from_secs_f64(-1.0), Value::Null);
+        assert_eq!(Value::from_millis_f64(f64::NAN), Value::Null);
+
+        let field = DataField::from_duration("latency", Duration::from_secs(2));
+        assert_eq!(field.meta, DataType::Duration);
+        assert_eq!(field.value.to_string(), "PT2S");
+    }
+
+    #[test]
+    fn test_value_duration_serde() {
+        let v = Value::Duration(Duration::new(1, 5));
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"Duration":1000000005}"#);
+        let back: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, v);
+    }
+
+    // ========== MacAddr tests ==========
+
+    #[test]
+    fn test_value_mac_addr_display_and_serde() {
+        let mac = MacAddrValue::from_str("00-1a-2b-3c-4d-5e").unwrap();
+        let v = Value::from(mac);
+        assert_eq!(v, Value::MacAddr([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]));
+        assert_eq!(v.to_string(), "00:1A:2B:3C:4D:5E");
+        assert_eq!(v.tag(), "MacAddr");
+        assert!(!v.is_empty());
+        assert_eq!(DataType::infer_from_value(&v), DataType::MacAddr);
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, r#"{"MacAddr":"00:1A:2B:3C:4D:5E"}"#);
+        let back: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, v);
+        assert!(serde_json::from_str::<Value>(r#"{"MacAddr":"zz"}"#).is_err());
+    }
+
+    // ========== Uuid tests ==========
+
+    #[test]
+    fn test_value_uuid_parse_display_serde() {
+        let text = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let id = UuidValue::from_str(text).unwrap();
+        assert_eq!(UuidValue::from_str(&text.to_uppercase()).unwrap(), id);
+        assert_eq!(UuidValue::from_str(&text.replace('-', "")).unwrap(), id);
+        let v = Value::from(id);
+        assert_eq!(v, Value::Uuid(id));
+        assert_eq!(v.to_string(), text);
+        assert_eq!(v.tag(), "Uuid");
+        assert!(!v.is_empty());
+        assert_eq!(DataType::infer_from_value(&v), DataType::Uuid);
+        for bad in [
+            "not-a-uuid",
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e5504410b1-426f-9247-bb680e5fe0c8-",
+            "+7e55044-10b1-426f-9247-bb680e5fe0c8",
+        ] {
+            assert!(UuidValue::from_str(bad).is_err(), "{bad}");
+        }
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, format!(r#"{{"Uuid":"{text}"}}"#));
+        let back: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, v);
+    }
+
+    #[cfg(feature = "uuid-support")]
+    #[test]
+    fn test_value_uuid_crate_conversion() {
+        let text = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let id = uuid::Uuid::parse_str(text).unwrap();
+        let v = Value::from(id);
+        assert_eq!(v, Value::Uuid(UuidValue::from_str(text).unwrap()));
+        assert_eq!(v.to_string(), id.hyphenated().to_string());
+
+        let field = DataField::from_uuid("trace_id", id);
+        assert_eq!(field.meta, DataType::Uuid);
+        assert_eq!(field.value, v);
+    }
+
+    // ========== to_sortable_bytes tests ==========
+
+    fn sort_by_bytes(mut values: Vec<Value>) -> Vec<Value> {
+        values.sort_by_key(Value::to_sortable_bytes);
+        values
+    }
+
+    #[test]
+    fn test_value_sortable_bytes_numeric() {
+        assert_eq!(
+            sort_by_bytes(vec![
+                Value::Digit(10),
+                Value::Digit(-5),
+                Value::Digit(i64::MAX),
+                Value::Digit(0),
+                Value::Digit(i64::MIN),
+            ]),
+            vec![
+                Value::Digit(i64::MIN),
+                Value::Digit(-5),
+                Value::Digit(0),
+                Value::Digit(10),
+                Value::Digit(i64::MAX),
+            ]
+        );
+        assert_eq!(
+            sort_by_bytes(vec![
+                Value::Float(1.5),
+                Value::Float(-0.5),
+                Value::Float(f64::INFINITY),
+                Value::Float(-100.0),
+                Value::Float(0.0),
+                Value::Float(f64::NEG_INFINITY),
+            ]),
+            vec![
+                Value::Float(f64::NEG_INFINITY),
+                Value::Float(-100.0),
+                Value::Float(-0.5),
+                Value::Float(0.0),
+                Value::Float(1.5),
+                Value::Float(f64::INFINITY),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_sortable_bytes_time_ip_chars() {
+        use chrono::NaiveDate;
+        let t = |y, ns| {
+            Value::Time(
+                NaiveDate::from_ymd_opt(y, 1, 1)
+                    .unwrap()
+                    .and_hms_nano_opt(0, 0, 0, ns)
+                    .unwrap(),
+            )
+        };
+        assert_eq!(
+            sort_by_bytes(vec![t(2024, 0), t(1960, 0), t(2024, 1), t(1970, 5)]),
+            vec![t(1960, 0), t(1970, 5), t(2024, 0), t(2024, 1)]
+        );
+
+        let ip = |s: &str| Value::IpAddr(s.parse().unwrap());
+        assert_eq!(
+            sort_by_bytes(vec![ip("::1"), ip("10.0.0.2"), ip("9.255.255.255")]),
+            vec![ip("9.255.255.255"), ip("10.0.0.2"), ip("::1")]
+        );
+
+        assert_eq!(
+            sort_by_bytes(vec![Value::from("b"), Value::from("ab"), Value::from("a")]),
+            vec![Value::from("a"), Value::from("ab"), Value::from("b")]
+        );
+    }
+
+    #[test]
+    fn test_value_duration_ordering() {
+        use std::cmp::Ordering;
+        use std::time::Duration;
+        let secs = |s: u64| Value::Duration(Duration::from_secs(s));
+        assert_eq!(secs(9).total_cmp(&secs(10)), Ordering::Less);
+        assert_eq!(
+            Value::Duration(Duration::from_millis(9_500)).total_cmp(&secs(9)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            sort_by_bytes(vec![
+                secs(10),
+                Value::Duration(Duration::from_nanos(9_000_000_001)),
+                secs(9),
+                Value::from("PT1S"),
+                secs(0),
+            ]),
+            vec![
+                secs(0),
+                secs(9),
+                Value::Duration(Duration::from_nanos(9_000_000_001)),
+                secs(10),
+                Value::from("PT1S"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_sortable_bytes_cross_type() {
+        let ip = Value::IpAddr("127.0.0.1".parse().unwrap());
+        let time = Value::Time(
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        );
+        assert_eq!(
+            sort_by_bytes(vec![
+                Value::from("x"),
+                ip.clone(),
+                time.clone(),
+                Value::Float(-1.0),
+                Value::Digit(100),
+                Value::Bool(true),
+                Value::Bool(false),
+                Value::Null,
+            ]),
+            vec![
+                Value::Null,
+                Value::Bool(false),
+                Value::Bool(true),
+                Value::Digit(100),
+                Value::Float(-1.0),
+                time,
+                ip,
+                Value::from("x"),
+            ]
+        );
+    }
+
+    // ========== array helper tests ==========
+
+    #[test]
+    fn test_array_push_and_get() {
+        let mut v = Value::Array(vec![DataField::from_digit("a", 1)]);
+        assert!(array_push(&mut v, DataField::from_digit("b", 2)));
+        assert!(v.push_to_array(DataField::from_chars("c", "x")));
+        assert_eq!(array_len(&v), Some(3));
+        assert_eq!(array_get(&v, 1).unwrap().get_name(), "b");
+        assert!(array_get(&v, 3).is_none());
+
+        let mut digit = Value::Digit(1);
+        assert!(!array_push(&mut digit, DataField::from_digit("b", 2)));
+        assert!(!digit.push_to_array(DataField::from_digit("b", 2)));
+        assert_eq!(digit, Value::Digit(1));
+        assert_eq!(array_len(&digit), None);
+        assert!(array_get(&digit, 0).is_none());
+    }
+
+    #[test]
+    fn test_array_remove_at() {
+        let mut v = Value::Array(vec![
+            DataField::from_digit("a", 1),
+            DataField::from_digit("b", 2),
+        ]);
+        assert!(array_remove_at(&mut v, 5).is_none());
+        let removed = array_remove_at(&mut v, 0).unwrap();
+        assert_eq!(removed.get_name(), "a");
+        assert_eq!(array_len(&v), Some(1));
+        assert_eq!(array_get(&v, 0).unwrap().get_name(), "b");
+        assert!(array_remove_at(&mut Value::Null, 0).is_none());
+    }
+
     #[test]
     fn test_array_value_from() {
         let arr: Vec<DataField> = vec![];
@@ -1,7 +1,9 @@
+use crate::model::error::ModelError;
 use smol_str::SmolStr;
 use std::{
     fmt::{Display, Formatter},
-    net::IpAddr,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
 };
 
 #[allow(dead_code)]
@@ -12,12 +14,34 @@ pub struct IpAddrValue(pub IpAddr);
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DomainValue(pub String);
 
+/// 反序列化时经 [`IpNetValue::new`] 校验前缀长度
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Eq)]
+#[serde(try_from = "IpNetRaw")]
 pub struct IpNetValue {
     addr: IpAddr,
     prefix_len: u8,
 }
 
+/// `IpNetValue` 的反序列化中间形态（未校验）
+#[derive(Deserialize)]
+struct IpNetRaw {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl TryFrom<IpNetRaw> for IpNetValue {
+    type Error = String;
+
+    fn try_from(raw: IpNetRaw) -> Result<Self, Self::Error> {
+        IpNetValue::new(raw.addr, raw.prefix_len).ok_or_else(|| {
+            format!(
+                "prefix length {} out of range for {}",
+                raw.prefix_len, raw.addr
+            )
+        })
+    }
+}
+
 impl IpNetValue {
     pub fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
         // Validate prefix length based on address family (v4: <=32, v6: <=128)
@@ -30,12 +54,115 @@ impl IpNetValue {
         }
         Some(Self { addr, prefix_len })
     }
+
+    /// `from` 与 `to` 均落在本网段内时返回 `true`；地址族不同时返回 `false`。
+    pub fn contains_range(&self, from: IpAddr, to: IpAddr) -> bool {
+        self.contains(from) && self.contains(to)
+    }
+
+    /// 两个网段存在公共地址时返回 `true`；地址族不同时返回 `false`。
+    pub fn overlaps(&self, other: &IpNetValue) -> bool {
+        if self.addr.is_ipv4() != other.addr.is_ipv4() {
+            return false;
+        }
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+        start <= other_end && other_start <= end
+    }
+
+    /// 本网段完全包含于 `other` 时返回 `true`；地址族不同时返回 `false`。
+    pub fn is_subset_of(&self, other: &IpNetValue) -> bool {
+        if self.addr.is_ipv4() != other.addr.is_ipv4() {
+            return false;
+        }
+        let (start, end) = self.bounds();
+        let (other_start, other_end) = other.bounds();
+        other_start <= start && end <= other_end
+    }
+
+    /// 网络地址：`addr` 的主机位清零
+    pub fn network_address(&self) -> IpAddr {
+        self.to_addr(self.bounds().0)
+    }
+
+    /// IPv4 广播地址（主机位全置 1）；IPv6 无广播地址，返回 `None`
+    pub fn broadcast_address(&self) -> Option<IpAddr> {
+        self.addr.is_ipv4().then(|| self.to_addr(self.bounds().1))
+    }
+
+    /// 网段内地址总数 `2^(max_prefix - prefix_len)`（含网络与广播地址）；
+    /// `::/0` 超出 `u128` 时取 `u128::MAX`
+    pub fn host_count(&self) -> u128 {
+        let width = addr_bits(self.addr).1;
+        1u128
+            .checked_shl(width - u32::from(self.prefix_len))
+            .unwrap_or(u128::MAX)
+    }
+
+    fn to_addr(&self, bits: u128) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(bits as u32)),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+        }
+    }
+
+    /// `ip` 落在本网段内（按 `prefix_len` 掩码比较）时返回 `true`；地址族不同时返回 `false`。
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        if self.addr.is_ipv4() != ip.is_ipv4() {
+            return false;
+        }
+        let (start, end) = self.bounds();
+        let ip = addr_bits(ip).0;
+        start <= ip && ip <= end
+    }
+
+    /// 网段首尾地址（忽略 `addr` 中的主机位），v4 地址按低 32 位表示
+    fn bounds(&self) -> (u128, u128) {
+        let (bits, width) = addr_bits(self.addr);
+        let host_bits = width - u32::from(self.prefix_len);
+        let host_mask = if host_bits == 128 {
+            u128::MAX
+        } else {
+            (1u128 << host_bits) - 1
+        };
+        (bits & !host_mask, bits | host_mask)
+    }
+}
+
+fn addr_bits(ip: IpAddr) -> (u128, u32) {
+    match ip {
+        IpAddr::V4(v4) => (u128::from(u32::from(v4)), 32),
+        IpAddr::V6(v6) => (u128::from(v6), 128),
+    }
 }
 impl Display for IpNetValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}/{}", self.addr, self.prefix_len)
     }
 }
+
+/// 解析 CIDR 文本（如 `"192.168.0.0/24"`），地址或前缀长度非法时返回 `ModelError::Parse`。
+impl FromStr for IpNetValue {
+    type Err = ModelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix) = s
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| ModelError::Parse(format!("invalid CIDR '{s}': missing '/prefix'")))?;
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|e| ModelError::Parse(format!("invalid CIDR '{s}': bad address: {e}")))?;
+        let prefix_len: u8 = prefix.parse().map_err(|e| {
+            ModelError::Parse(format!("invalid CIDR '{s}': bad prefix length: {e}"))
+        })?;
+        IpNetValue::new(addr, prefix_len).ok_or_else(|| {
+            ModelError::Parse(format!(
+                "invalid CIDR '{s}': prefix length {prefix_len} out of range"
+            ))
+        })
+    }
+}
 // Comparison impl moved to orion_exp adapters.
 
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
@@ -45,6 +172,32 @@ impl Display for DomainT {
         write!(f, "{}", self.0)
     }
 }
+impl DomainT {
+    /// 校验后构造：不能为空，各 label 非空且不超过 63 字符
+    pub fn try_new(s: &str) -> Result<DomainT, ModelError> {
+        if s.is_empty() {
+            return Err(ModelError::Validation("empty domain".into()));
+        }
+        if let Some(label) = s.split('.').find(|l| l.is_empty() || l.len() > 63) {
+            return Err(ModelError::Validation(format!(
+                "invalid domain '{s}': label '{label}' must be 1..=63 chars"
+            )));
+        }
+        Ok(DomainT(s.into()))
+    }
+
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('.')
+    }
+
+    /// 最后一个 label；不含 `.` 时返回 `None`
+    pub fn tld(&self) -> Option<&str> {
+        self.0
+            .rsplit_once('.')
+            .map(|(_, tld)| tld)
+            .filter(|t| !t.is_empty())
+    }
+}
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct UrlValue(pub SmolStr);
 impl Display for UrlValue {
@@ -52,6 +205,38 @@ impl Display for UrlValue {
         write!(f, "{}", self.0)
     }
 }
+/// 轻量解析 `scheme://[user@]host[:port]/path?query#fragment`，不做完整 RFC 3986 校验。
+impl UrlValue {
+    /// `://` 之前的部分；无 scheme 时返回 `None`
+    pub fn scheme(&self) -> Option<&str> {
+        self.split_authority().0
+    }
+
+    /// 去掉 userinfo 与端口后的主机名（IPv6 去掉方括号）；无 scheme 或主机为空时返回 `None`
+    pub fn host(&self) -> Option<&str> {
+        let authority = self.split_authority().1?;
+        let host_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+        let host = match host_port.strip_prefix('[') {
+            Some(v6) => v6.split_once(']').map_or(v6, |(h, _)| h),
+            None => host_port.split_once(':').map_or(host_port, |(h, _)| h),
+        };
+        (!host.is_empty()).then_some(host)
+    }
+
+    /// authority 之后、`?`/`#` 之前的路径；无 scheme 时为整个字符串去掉 query 与 fragment
+    pub fn path(&self) -> &str {
+        let rest = self.split_authority().2;
+        rest.split(['?', '#']).next().unwrap_or_default()
+    }
+
+    fn split_authority(&self) -> (Option<&str>, Option<&str>, &str) {
+        let Some((scheme, rest)) = self.0.split_once("://") else {
+            return (None, None, self.0.as_str());
+        };
+        let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        (Some(scheme), Some(&rest[..end]), &rest[end..])
+    }
+}
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct EmailT(pub SmolStr);
 impl Display for EmailT {
@@ -59,12 +244,89 @@ impl Display for EmailT {
         write!(f, "{}", self.0)
     }
 }
+impl EmailT {
+    /// 校验后构造：恰好一个 `@`，且用户名与域名部分均非空
+    pub fn try_new(s: &str) -> Result<EmailT, ModelError> {
+        match s.split_once('@') {
+            Some((user, domain))
+                if !user.is_empty() && !domain.is_empty() && !domain.contains('@') =>
+            {
+                Ok(EmailT(s.into()))
+            }
+            _ => Err(ModelError::Validation(format!("invalid email '{s}'"))),
+        }
+    }
+
+    /// `@` 之前的部分；未经校验且不含 `@` 时为整个字符串
+    pub fn username(&self) -> &str {
+        self.0.split_once('@').map_or(self.0.as_str(), |(u, _)| u)
+    }
+
+    /// `@` 之后的部分；不含 `@` 时为空串
+    pub fn domain_part(&self) -> &str {
+        self.0.split_once('@').map_or("", |(_, d)| d)
+    }
+}
+
+/// MAC 地址的解析与格式化；值本身以 `[u8; 6]` 保存在 `Value::MacAddr` 中。
+pub struct MacAddrValue;
+
+impl MacAddrValue {
+    /// 支持 `AA:BB:CC:DD:EE:FF`、`AA-BB-CC-DD-EE-FF` 与 `AABBCCDDEEFF` 三种写法，大小写不敏感
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<[u8; 6], ModelError> {
+        let invalid = || ModelError::Parse(format!("invalid mac address '{s}'"));
+        let text = s.trim();
+        let groups: Vec<&str> = if text.contains(':') {
+            text.split(':').collect()
+        } else if text.contains('-') {
+            text.split('-').collect()
+        } else if text.len() == 12 && text.is_ascii() {
+            (0..6).map(|i| &text[i * 2..i * 2 + 2]).collect()
+        } else {
+            return Err(invalid());
+        };
+        if groups.len() != 6 {
+            return Err(invalid());
+        }
+        let mut mac = [0u8; 6];
+        for (slot, group) in mac.iter_mut().zip(groups) {
+            if group.len() != 2 || !group.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(invalid());
+            }
+            *slot = u8::from_str_radix(group, 16).map_err(|_| invalid())?;
+        }
+        Ok(mac)
+    }
+
+    /// 冒号分隔的大写十六进制，如 `AA:BB:CC:DD:EE:FF`
+    pub fn format(mac: &[u8; 6]) -> String {
+        mac.iter()
+            .map(|b| format!("{b:02X}"))
+            .collect::<Vec<_>>()
+            .join(":")
+    }
+}
+
+/// `Value::MacAddr` 的 serde 表示：序列化为 [`MacAddrValue::format`] 字符串
+pub(crate) mod mac_addr_serde {
+    use super::MacAddrValue;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(mac: &[u8; 6], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&MacAddrValue::format(mac))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 6], D::Error> {
+        let text = String::deserialize(deserializer)?;
+        MacAddrValue::from_str(&text).map_err(serde::de::Error::custom)
+    }
+}
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::net::{Ipv4Addr, Ipv6Addr};
 
     // ========== IpNetValue tests ==========
 
@@ -125,6 +387,112 @@ mod tests {
         assert_eq!(format!("{}", net), "10.0.0.0/8");
     }
 
+    fn net(s: &str, prefix_len: u8) -> IpNetValue {
+        IpNetValue::new(s.parse().unwrap(), prefix_len).unwrap()
+    }
+
+    #[test]
+    fn test_ip_net_value_from_str() {
+        let host: IpNetValue = "192.168.1.7/32".parse().unwrap();
+        assert_eq!(host, net("192.168.1.7", 32));
+        let v6 = IpNetValue::from_str("2001:db8::/32").unwrap();
+        assert_eq!(v6.to_string(), "2001:db8::/32");
+        assert_eq!(
+            " 10.0.0.0/8 ".parse::<IpNetValue>().unwrap(),
+            net("10.0.0.0", 8)
+        );
+
+        for bad in [
+            "10.0.0.0/33",
+            "::/129",
+            "10.0.0.0",
+            "10.0.0/8",
+            "10.0.0.0/x",
+            "/8",
+        ] {
+            let err = bad.parse::<IpNetValue>().unwrap_err();
+            assert!(matches!(err, ModelError::Parse(_)), "{bad}");
+        }
+        assert!(
+            "10.0.0.0/33"
+                .parse::<IpNetValue>()
+                .unwrap_err()
+                .to_string()
+                .contains("out of range")
+        );
+    }
+
+    #[test]
+    fn test_ip_net_value_contains() {
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        let block: IpNetValue = "192.168.0.0/24".parse().unwrap();
+        assert!(block.contains(ip("192.168.0.0")));
+        assert!(block.contains(ip("192.168.0.255")));
+        assert!(!block.contains(ip("192.168.1.0")));
+        assert!(!block.contains(ip("192.167.255.255")));
+        assert!(!block.contains(ip("::ffff:192.168.0.1")));
+
+        let host: IpNetValue = "10.1.2.3/32".parse().unwrap();
+        assert!(host.contains(ip("10.1.2.3")));
+        assert!(!host.contains(ip("10.1.2.4")));
+
+        let v6: IpNetValue = "2001:db8::/64".parse().unwrap();
+        assert!(v6.contains(ip("2001:db8::ffff:ffff:ffff:ffff")));
+        assert!(!v6.contains(ip("2001:db8:0:1::")));
+        assert!("::/0".parse::<IpNetValue>().unwrap().contains(ip("::1")));
+    }
+
+    #[test]
+    fn test_ip_net_value_derived_addresses() {
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        let block: IpNetValue = "192.168.1.77/24".parse().unwrap();
+        assert_eq!(block.network_address(), ip("192.168.1.0"));
+        assert_eq!(block.broadcast_address(), Some(ip("192.168.1.255")));
+        assert_eq!(block.host_count(), 256);
+
+        let host: IpNetValue = "10.0.0.1/32".parse().unwrap();
+        assert_eq!(host.network_address(), ip("10.0.0.1"));
+        assert_eq!(host.broadcast_address(), Some(ip("10.0.0.1")));
+        assert_eq!(host.host_count(), 1);
+        assert_eq!(net("0.0.0.0", 0).host_count(), 1 << 32);
+
+        let v6: IpNetValue = "2001:db8::1/64".parse().unwrap();
+        assert_eq!(v6.network_address(), ip("2001:db8::"));
+        assert_eq!(v6.broadcast_address(), None);
+        assert_eq!(v6.host_count(), 1 << 64);
+        assert_eq!(net("::", 0).host_count(), u128::MAX);
+    }
+
+    #[test]
+    fn test_ip_net_value_overlaps() {
+        assert!(net("10.0.0.0", 24).overlaps(&net("10.0.0.128", 25)));
+        assert!(net("10.0.0.128", 25).overlaps(&net("10.0.0.0", 24)));
+        assert!(!net("10.0.0.0", 24).overlaps(&net("10.0.1.0", 24)));
+        assert!(net("0.0.0.0", 0).overlaps(&net("192.168.1.1", 32)));
+        assert!(!net("10.0.0.0", 8).overlaps(&net("::", 0)));
+    }
+
+    #[test]
+    fn test_ip_net_value_contains_range() {
+        let block = net("10.0.0.0", 24);
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        assert!(block.contains_range(ip("10.0.0.1"), ip("10.0.0.255")));
+        assert!(!block.contains_range(ip("10.0.0.1"), ip("10.0.1.0")));
+        assert!(!block.contains_range(ip("::1"), ip("::2")));
+        // 主机位非零的地址按所在网段处理
+        assert!(net("10.0.0.77", 24).contains_range(ip("10.0.0.0"), ip("10.0.0.5")));
+        assert!(net("2001:db8::", 32).contains_range(ip("2001:db8::1"), ip("2001:db8:ffff::1")));
+    }
+
+    #[test]
+    fn test_ip_net_value_is_subset_of() {
+        assert!(net("10.0.0.128", 25).is_subset_of(&net("10.0.0.0", 24)));
+        assert!(net("10.0.0.0", 24).is_subset_of(&net("10.0.0.0", 24)));
+        assert!(!net("10.0.0.0", 24).is_subset_of(&net("10.0.0.128", 25)));
+        assert!(net("2001:db8:1::", 48).is_subset_of(&net("::", 0)));
+        assert!(!net("10.0.0.0", 24).is_subset_of(&net("::", 0)));
+    }
+
     #[test]
     fn test_ip_net_value_clone_eq() {
         let ip = IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0));
@@ -196,6 +564,84 @@ mod tests {
         assert_eq!(e1, e2);
     }
 
+    #[test]
+    fn test_email_t_try_new_and_parts() {
+        let email = EmailT::try_new("alice@example.com").unwrap();
+        assert_eq!(email.username(), "alice");
+        assert_eq!(email.domain_part(), "example.com");
+
+        for bad in ["", "alice", "@example.com", "alice@", "a@b@c"] {
+            assert!(EmailT::try_new(bad).is_err(), "{bad}");
+        }
+        let raw = EmailT("no-at".into());
+        assert_eq!(raw.username(), "no-at");
+        assert_eq!(raw.domain_part(), "");
+    }
+
+    #[test]
+    fn test_domain_t_try_new_and_labels() {
+        let domain = DomainT::try_new("www.example.com").unwrap();
+        assert_eq!(
+            domain.labels().collect::<Vec<_>>(),
+            vec!["www", "example", "com"]
+        );
+        assert_eq!(domain.tld(), Some("com"));
+        assert_eq!(DomainT::try_new("localhost").unwrap().tld(), None);
+
+        let long = "a".repeat(64);
+        for bad in ["", "a..b", ".com", "example.", long.as_str()] {
+            assert!(DomainT::try_new(bad).is_err(), "{bad}");
+        }
+        assert!(DomainT::try_new(&"a".repeat(63)).is_ok());
+    }
+
+    #[test]
+    fn test_url_value_parts() {
+        let url = UrlValue("https://user@api.example.com:8443/v1/items?id=1#top".into());
+        assert_eq!(url.scheme(), Some("https"));
+        assert_eq!(url.host(), Some("api.example.com"));
+        assert_eq!(url.path(), "/v1/items");
+
+        let v6 = UrlValue("http://[::1]:8080".into());
+        assert_eq!(v6.host(), Some("::1"));
+        assert_eq!(v6.path(), "");
+
+        let bare = UrlValue("/local/path?x=1".into());
+        assert_eq!(bare.scheme(), None);
+        assert_eq!(bare.host(), None);
+        assert_eq!(bare.path(), "/local/path");
+
+        assert_eq!(UrlValue("file:///etc/hosts".into()).host(), None);
+        assert_eq!(UrlValue("file:///etc/hosts".into()).path(), "/etc/hosts");
+    }
+
+    // ========== MacAddrValue tests ==========
+
+    #[test]
+    fn test_mac_addr_parse_formats() {
+        let expected = [0xAA, 0xBB, 0xCC, 0x0D, 0xEE, 0xFF];
+        for text in ["AA:BB:CC:0D:EE:FF", "aa-bb-cc-0d-ee-ff", "aabbcc0DeeFF"] {
+            assert_eq!(MacAddrValue::from_str(text).unwrap(), expected, "{text}");
+        }
+        assert_eq!(MacAddrValue::format(&expected), "AA:BB:CC:0D:EE:FF");
+
+        for bad in [
+            "",
+            "AA:BB:CC:DD:EE",
+            "AA:BB:CC:DD:EE:FF:00",
+            "A:BB:CC:DD:EE:FFF",
+            "GG:BB:CC:DD:EE:FF",
+            "aabbccddeef",
+            "+a:bb:cc:dd:ee:ff",
+            "+abbccddeeff",
+        ] {
+            assert!(
+                matches!(MacAddrValue::from_str(bad), Err(ModelError::Parse(_))),
+                "{bad}"
+            );
+        }
+    }
+
     // ========== Serde tests ==========
 
     #[test]
@@ -205,6 +651,14 @@ mod tests {
         let json = serde_json::to_string(&net).unwrap();
         let parsed: IpNetValue = serde_json::from_str(&json).unwrap();
         assert_eq!(net, parsed);
+
+        for bad in [
+            r#"{"addr":"10.0.0.0","prefix_len":40}"#,
+            r#"{"addr":"::","prefix_len":129}"#,
+        ] {
+            let err = serde_json::from_str::<IpNetValue>(bad).unwrap_err();
+            assert!(err.to_string().contains("out of range"), "{bad}: {err}");
+        }
     }
 
     #[test]
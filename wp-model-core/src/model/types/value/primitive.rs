@@ -1,7 +1,11 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
 
 use chrono::NaiveDateTime;
 
+use crate::model::error::ModelError;
+
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Eq)]
 pub struct HexT(pub u128);
 
@@ -10,7 +14,199 @@ impl Display for HexT {
         write!(f, "{:#X}", self.0)
     }
 }
+
+/// 解析十六进制文本，`0x`/`0X` 前缀可选，大小写不敏感；非法字符或超出 `u128` 返回 `ModelError::Parse`。
+impl FromStr for HexT {
+    type Err = ModelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ModelError::Parse(format!("invalid hex '{s}'")));
+        }
+        u128::from_str_radix(digits, 16)
+            .map(HexT)
+            .map_err(|e| ModelError::Parse(format!("invalid hex '{s}': {e}")))
+    }
+}
+
+impl HexT {
+    /// 大端字节序，去掉前导 0 字节；值为 0 时返回 `[0]`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let bytes = self.0.to_be_bytes();
+        let start = bytes
+            .iter()
+            .position(|b| *b != 0)
+            .unwrap_or(bytes.len() - 1);
+        bytes[start..].to_vec()
+    }
+
+    /// 按大端字节序读取，最多 16 字节；空切片为 0
+    pub fn from_bytes(b: &[u8]) -> Result<HexT, ModelError> {
+        if b.len() > 16 {
+            return Err(ModelError::Parse(format!(
+                "hex bytes too long: {} > 16",
+                b.len()
+            )));
+        }
+        let mut buf = [0u8; 16];
+        buf[16 - b.len()..].copy_from_slice(b);
+        Ok(HexT(u128::from_be_bytes(buf)))
+    }
+
+    /// 小写形式，如 `0xff`（`Display` 输出 `0xFF`）
+    pub fn to_lower_hex_string(&self) -> String {
+        format!("{:#x}", self.0)
+    }
+}
+
+/// UUID 的解析与格式化；值本身以 `[u8; 16]` 保存在 `Value::Uuid` 中，不依赖 `uuid` crate。
+pub struct UuidValue;
+
+impl UuidValue {
+    /// 支持连字符格式 `67e55044-10b1-426f-9247-bb680e5fe0c8` 与 32 位无连字符格式，大小写不敏感
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<[u8; 16], ModelError> {
+        let invalid = || ModelError::Parse(format!("invalid uuid '{s}'"));
+        let text = s.trim();
+        let hex: String = match text.len() {
+            36 => {
+                let dashes_ok = text
+                    .bytes()
+                    .enumerate()
+                    .all(|(i, b)| matches!(i, 8 | 13 | 18 | 23) == (b == b'-'));
+                if !dashes_ok {
+                    return Err(invalid());
+                }
+                text.chars().filter(|c| *c != '-').collect()
+            }
+            32 => text.to_string(),
+            _ => return Err(invalid()),
+        };
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let mut id = [0u8; 16];
+        for (i, slot) in id.iter_mut().enumerate() {
+            *slot = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(id)
+    }
+
+    /// 小写连字符格式，如 `67e55044-10b1-426f-9247-bb680e5fe0c8`
+    pub fn format(id: &[u8; 16]) -> String {
+        use std::fmt::Write;
+        let mut out = String::with_capacity(36);
+        for (i, b) in id.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                out.push('-');
+            }
+            let _ = write!(out, "{b:02x}");
+        }
+        out
+    }
+}
+
 pub type DigitValue = i64;
 pub type FloatValue = f64;
 pub type DateTimeValue = NaiveDateTime;
+
+/// ISO 8601 风格的时长文本，仅使用秒：`PT1.234S`、`PT90S`，小数部分去掉末尾的 0
+pub(crate) fn iso8601_duration(d: &Duration) -> String {
+    let nanos = d.subsec_nanos();
+    if nanos == 0 {
+        return format!("PT{}S", d.as_secs());
+    }
+    let frac = format!("{nanos:09}");
+    format!("PT{}.{}S", d.as_secs(), frac.trim_end_matches('0'))
+}
+
+/// `Value::Duration` 的 serde 表示：纳秒数（`u64`，超出范围时饱和）
+pub(crate) mod duration_nanos_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(u64::try_from(d.as_nanos()).unwrap_or(u64::MAX))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+/// `Value::Uuid` 的 serde 表示：小写连字符格式的字符串
+pub(crate) mod uuid_serde {
+    use super::UuidValue;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(id: &[u8; 16], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&UuidValue::format(id))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 16], D::Error> {
+        let text = String::deserialize(deserializer)?;
+        UuidValue::from_str(&text).map_err(serde::de::Error::custom)
+    }
+}
 use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ========== HexT parse tests ==========
+
+    #[test]
+    fn test_hex_t_from_str() {
+        assert_eq!("0xFF".parse::<HexT>().unwrap(), HexT(255));
+        assert_eq!("ff".parse::<HexT>().unwrap(), HexT(255));
+        assert_eq!(HexT::from_str("0XDEADBEEF").unwrap(), HexT(0xDEAD_BEEF));
+        assert_eq!(HexT::from_str(&"f".repeat(32)).unwrap(), HexT(u128::MAX));
+
+        for bad in ["", "0x", "0xZZ", "+ff", "-1", "0x 1", &"f".repeat(33)] {
+            assert!(
+                matches!(bad.parse::<HexT>(), Err(ModelError::Parse(_))),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_hex_t_display_round_trip() {
+        let hex = HexT(0xABCD);
+        assert_eq!(hex.to_string(), "0xABCD");
+        assert_eq!(hex.to_lower_hex_string(), "0xabcd");
+        assert_eq!(hex.to_string().parse::<HexT>().unwrap(), hex);
+        assert_eq!(hex.to_lower_hex_string().parse::<HexT>().unwrap(), hex);
+    }
+
+    // ========== HexT bytes tests ==========
+
+    #[test]
+    fn test_hex_t_bytes() {
+        assert_eq!(HexT(0x01_02_03).to_bytes(), vec![1, 2, 3]);
+        assert_eq!(HexT(0).to_bytes(), vec![0]);
+        assert_eq!(HexT(u128::MAX).to_bytes(), vec![0xFF; 16]);
+
+        assert_eq!(HexT::from_bytes(&[1, 2, 3]).unwrap(), HexT(0x01_02_03));
+        assert_eq!(HexT::from_bytes(&[]).unwrap(), HexT(0));
+        assert_eq!(HexT::from_bytes(&[0, 0, 7]).unwrap(), HexT(7));
+        assert!(HexT::from_bytes(&[0; 17]).is_err());
+
+        let hex = HexT(0xDEAD_BEEF_0000_0001);
+        assert_eq!(HexT::from_bytes(&hex.to_bytes()).unwrap(), hex);
+    }
+
+    // ========== duration format tests ==========
+
+    #[test]
+    fn test_iso8601_duration() {
+        assert_eq!(iso8601_duration(&Duration::from_millis(1234)), "PT1.234S");
+        assert_eq!(iso8601_duration(&Duration::from_secs(90)), "PT90S");
+        assert_eq!(iso8601_duration(&Duration::ZERO), "PT0S");
+        assert_eq!(iso8601_duration(&Duration::from_nanos(5)), "PT0.000000005S");
+    }
+}